@@ -11,12 +11,66 @@ pub struct ParsedConfig {
   pub gaps: GapsConfig,
   pub general: GeneralConfig,
   pub keybindings: Vec<KeybindingConfig>,
+  pub mouse_bindings: MouseBindingsConfig,
   pub window_behavior: WindowBehaviorConfig,
   pub window_effects: WindowEffectsConfig,
   pub window_rules: Vec<WindowRuleConfig>,
   pub workspaces: Vec<WorkspaceConfig>,
 }
 
+/// Openbox-style mouse-binding table: a single modifier key gates every
+/// drag/click action below, and each mouse button is mapped to an
+/// action independently. See `events::handle_mouse_move`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct MouseBindingsConfig {
+  pub modifier: MouseModifier,
+  pub left_drag: MouseDragAction,
+  pub right_drag: MouseDragAction,
+  pub middle_drag: MouseDragAction,
+  pub double_click: MouseClickAction,
+}
+
+impl Default for MouseBindingsConfig {
+  fn default() -> Self {
+    MouseBindingsConfig {
+      modifier: MouseModifier::Alt,
+      left_drag: MouseDragAction::Move,
+      right_drag: MouseDragAction::Resize,
+      middle_drag: MouseDragAction::None,
+      double_click: MouseClickAction::ToggleFloating,
+    }
+  }
+}
+
+/// Modifier key that must be held for any `MouseBindingsConfig` drag or
+/// click action to engage.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MouseModifier {
+  Alt,
+  Super,
+  Shift,
+  Ctrl,
+}
+
+/// Action performed while a mouse button is held and the cursor moves.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MouseDragAction {
+  None,
+  Move,
+  Resize,
+}
+
+/// Action performed on a modifier + double-click.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MouseClickAction {
+  None,
+  ToggleFloating,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all(serialize = "camelCase"))]
 pub struct BindingModeConfig {
@@ -65,6 +119,12 @@ pub struct GeneralConfig {
   pub show_all_in_taskbar: bool,
   // [Added] Animation configuration
   pub animations: AnimationConfig,
+  /// Default tiling layout for workspaces that don't set their own
+  /// `WorkspaceConfig.layout`.
+  pub default_layout: LayoutConfig,
+  /// On-screen display shown briefly whenever the workspace displayed
+  /// on a monitor changes. See `commands::general::workspace_osd`.
+  pub workspace_osd: WorkspaceOsdConfig,
 }
 
 impl Default for GeneralConfig {
@@ -79,10 +139,69 @@ impl Default for GeneralConfig {
       hide_method: HideMethod::Cloak,
       show_all_in_taskbar: false,
       animations: AnimationConfig::default(),
+      default_layout: LayoutConfig::default(),
+      workspace_osd: WorkspaceOsdConfig::default(),
+    }
+  }
+}
+
+/// Configures the brief overlay shown on a monitor whenever the
+/// workspace it displays changes, so keybinding-driven workspace
+/// switches get visual feedback without a separate bar.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct WorkspaceOsdConfig {
+  pub enabled: bool,
+  pub position: OsdPosition,
+  /// How long the overlay stays fully visible before fading out.
+  pub timeout_ms: u64,
+  /// Distance in pixels from whichever monitor edge `position` anchors
+  /// to. Ignored when `position` is `Center`.
+  pub edge_offset: i32,
+  pub font_family: String,
+  pub font_size: i32,
+  pub text_color: Color,
+  pub background_color: Color,
+}
+
+impl Default for WorkspaceOsdConfig {
+  fn default() -> Self {
+    WorkspaceOsdConfig {
+      enabled: false,
+      position: OsdPosition::default(),
+      timeout_ms: 1000,
+      edge_offset: 48,
+      font_family: "Segoe UI".to_string(),
+      font_size: 24,
+      text_color: Color {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 255,
+      },
+      background_color: Color {
+        r: 30,
+        g: 30,
+        b: 30,
+        a: 220,
+      },
     }
   }
 }
 
+/// Anchor point for `WorkspaceOsdConfig` within the monitor's full rect
+/// (not just its work area).
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OsdPosition {
+  TopLeft,
+  TopRight,
+  BottomLeft,
+  BottomRight,
+  #[default]
+  Center,
+}
+
 // [Added] Struct for animation settings
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
@@ -90,6 +209,7 @@ pub struct AnimationConfig {
     pub enabled: bool,
     pub duration_ms: u64,
     pub fps: u64,
+    pub easing: EasingConfig,
 }
 
 impl Default for AnimationConfig {
@@ -98,10 +218,33 @@ impl Default for AnimationConfig {
             enabled: true,
             duration_ms: 150, // Default to a snappy but smooth feel
             fps: 144, // High refresh rate by default
+            easing: EasingConfig::default(),
         }
     }
 }
 
+/// Easing curve applied to a window animation's normalized `0..1`
+/// progress each frame. `Spring` is qualitatively different from the
+/// rest: instead of mapping a fixed `duration_ms` through a curve, it
+/// integrates position directly every tick via `stiffness`/`damping`
+/// and keeps animating until the window settles near its target,
+/// overshooting if `damping` is loose enough to allow it.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EasingConfig {
+  Linear,
+  EaseOutCubic,
+  EaseInOutQuad,
+  EaseOutBack,
+  Spring { stiffness: f32, damping: f32 },
+}
+
+impl Default for EasingConfig {
+  fn default() -> Self {
+    Self::EaseOutCubic
+  }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
 pub struct CursorJumpConfig {
@@ -207,6 +350,19 @@ pub struct WindowEffectConfig {
 pub struct BorderEffectConfig {
   pub enabled: bool,
   pub color: Color,
+  /// Draws a custom topmost overlay window around the target instead of
+  /// relying on DWM's accent border (`NativeWindow::set_border_color`),
+  /// which is limited to a single pixel and unavailable pre-Windows 11.
+  /// See `commands::general::border_overlay`.
+  pub native_drawn: bool,
+  /// Thickness in pixels of the drawn border overlay. Only applies when
+  /// `native_drawn` is enabled.
+  pub thickness: i32,
+  /// How far to inset the drawn rect from the window's reported frame,
+  /// to compensate for Win32's invisible resize-border region so the
+  /// drawn edge hugs the *visible* window edge. Only applies when
+  /// `native_drawn` is enabled.
+  pub border_offset: i32,
 }
 
 impl Default for BorderEffectConfig {
@@ -219,6 +375,9 @@ impl Default for BorderEffectConfig {
         b: 255,
         a: 255,
       },
+      native_drawn: false,
+      thickness: 2,
+      border_offset: 1,
     }
   }
 }
@@ -263,6 +422,37 @@ pub struct WindowRuleConfig {
   pub on: Vec<WindowRuleEvent>,
   #[serde(default = "default_bool::<true>")]
   pub run_once: bool,
+  /// Initial floating geometry to apply when this rule matches on the
+  /// `Manage` event, overriding the default 90%-of-workspace centered
+  /// placement. Ignored for other `on` events.
+  #[serde(default)]
+  pub geometry: Option<InitialGeometryConfig>,
+}
+
+/// Size and position to open a newly-managed floating window at,
+/// declared through a `Manage` window rule (e.g. to pin a
+/// picture-in-picture player to a corner).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct InitialGeometryConfig {
+  pub x: Option<LengthValue>,
+  pub y: Option<LengthValue>,
+  pub width: Option<LengthValue>,
+  pub height: Option<LengthValue>,
+  pub anchor: GeometryAnchor,
+}
+
+/// Corner/edge of the workspace that `x`/`y` in `InitialGeometryConfig`
+/// are measured from.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GeometryAnchor {
+  #[default]
+  Center,
+  TopLeft,
+  TopRight,
+  BottomLeft,
+  BottomRight,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
@@ -271,6 +461,106 @@ pub struct WindowMatchConfig {
   pub window_process: Option<MatchType>,
   pub window_class: Option<MatchType>,
   pub window_title: Option<MatchType>,
+  pub monitor: Option<MonitorMatchConfig>,
+  pub workspace: Option<String>,
+  pub is_floating: Option<bool>,
+  pub is_fullscreen: Option<bool>,
+  pub min_width: Option<i32>,
+  pub max_width: Option<i32>,
+  pub min_height: Option<i32>,
+  pub max_height: Option<i32>,
+  /// OR group: matches if any of these sub-configs match.
+  pub any_of: Vec<WindowMatchConfig>,
+  /// AND group: matches if all of these sub-configs match. The flat
+  /// top-level fields on this same `WindowMatchConfig` are sugar for an
+  /// implicit entry in this group.
+  pub all_of: Vec<WindowMatchConfig>,
+  /// Matches if none of these sub-configs match.
+  pub none_of: Vec<WindowMatchConfig>,
+}
+
+/// Identifies a monitor by either its configured index or its
+/// device/friendly name.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum MonitorMatchConfig {
+  Index(u32),
+  Name(String),
+}
+
+/// Window attributes available for rule matching, gathered by the
+/// caller from the live `WindowContainer`/`NativeWindow` and the
+/// current `Monitor`/`Workspace` it belongs to. Kept independent of the
+/// window-tree types so the matching logic here has no dependency on
+/// the `wm` crate.
+#[derive(Clone, Debug, Default)]
+pub struct WindowMatchAttrs {
+  pub process_name: String,
+  pub class_name: String,
+  pub title: String,
+  pub monitor_index: u32,
+  pub monitor_name: String,
+  pub workspace_name: String,
+  pub is_floating: bool,
+  pub is_fullscreen: bool,
+  pub width: i32,
+  pub height: i32,
+}
+
+impl WindowMatchConfig {
+  /// Whether `attrs` satisfies this match config. The flat fields
+  /// (`window_process`, `monitor`, dimension bounds, etc.) are ANDed
+  /// together, then combined with any nested `any_of`/`all_of`/`none_of`
+  /// groups, which is what lets the flat form act as sugar for
+  /// `all_of: [{ ...flat fields... }]`.
+  #[must_use]
+  pub fn is_match(&self, attrs: &WindowMatchAttrs) -> bool {
+    let flat_matches = self.flat_fields_match(attrs);
+
+    let any_of_matches = self.any_of.is_empty()
+      || self.any_of.iter().any(|rule| rule.is_match(attrs));
+
+    let all_of_matches =
+      self.all_of.iter().all(|rule| rule.is_match(attrs));
+
+    let none_of_matches =
+      self.none_of.iter().all(|rule| !rule.is_match(attrs));
+
+    flat_matches && any_of_matches && all_of_matches && none_of_matches
+  }
+
+  fn flat_fields_match(&self, attrs: &WindowMatchAttrs) -> bool {
+    self
+      .window_process
+      .as_ref()
+      .map_or(true, |m| m.is_match(&attrs.process_name))
+      && self
+        .window_class
+        .as_ref()
+        .map_or(true, |m| m.is_match(&attrs.class_name))
+      && self
+        .window_title
+        .as_ref()
+        .map_or(true, |m| m.is_match(&attrs.title))
+      && self.monitor.as_ref().map_or(true, |monitor| match monitor {
+        MonitorMatchConfig::Index(index) => *index == attrs.monitor_index,
+        MonitorMatchConfig::Name(name) => {
+          name.trim().eq_ignore_ascii_case(attrs.monitor_name.trim())
+        }
+      })
+      && self
+        .workspace
+        .as_ref()
+        .map_or(true, |name| name == &attrs.workspace_name)
+      && self.is_floating.map_or(true, |value| value == attrs.is_floating)
+      && self
+        .is_fullscreen
+        .map_or(true, |value| value == attrs.is_fullscreen)
+      && self.min_width.map_or(true, |min| attrs.width >= min)
+      && self.max_width.map_or(true, |max| attrs.width <= max)
+      && self.min_height.map_or(true, |min| attrs.height >= min)
+      && self.max_height.map_or(true, |max| attrs.height <= max)
+  }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -315,8 +605,52 @@ pub struct WorkspaceConfig {
   pub display_name: Option<String>,
   #[serde(default)]
   pub bind_to_monitor: Option<u32>,
+  /// Preferred output (monitor device/friendly name) for this workspace,
+  /// matched case-insensitively. Akin to niri's `open_on_output`. At
+  /// startup, `WmState::reconcile_workspace_outputs` moves the workspace
+  /// onto that monitor if it is connected; otherwise the workspace stays
+  /// on the monitor it opened on. The preference is not re-applied on
+  /// display changes or when the workspace is created later. See
+  /// `WmState::monitor_by_name`.
+  #[serde(default)]
+  pub open_on_output: Option<String>,
   #[serde(default = "default_bool::<false>")]
   pub keep_alive: bool,
+  /// Tiling layout to use for new windows on this workspace. Falls back
+  /// to `GeneralConfig.default_layout` when unset.
+  #[serde(default)]
+  pub layout: Option<LayoutConfig>,
+  /// Arranges this workspace's tiling windows as columns on an infinite
+  /// horizontal strip (PaperWM/niri-style) instead of the standard BSP
+  /// tiling tree. See `WmState::scrolling_layouts`.
+  #[serde(default = "default_bool::<false>")]
+  pub scrolling: bool,
+}
+
+/// Selectable tiling layout algorithms, akin to dynamic X11 WMs like
+/// herbstluftwm (dwindle) and wzrd (master-stack).
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LayoutConfig {
+  /// Insert next to the focused window on whichever side of it the
+  /// cursor is hovering, splitting in the axis the cursor is closer to.
+  FocusedMouse,
+  /// BSP layout that splits the focused window's leaf, alternating
+  /// split direction by depth so windows dwindle into a corner.
+  Dwindle,
+  /// Like `Dwindle`, but alternates the insert-after side each level so
+  /// new windows wind around in a spiral instead of a single corner.
+  Spiral,
+  /// First window occupies a master region sized by `master_ratio`; all
+  /// others are inserted into the master's own split as its siblings,
+  /// not grouped into a split of their own.
+  MasterStack { master_ratio: f32 },
+}
+
+impl Default for LayoutConfig {
+  fn default() -> Self {
+    Self::FocusedMouse
+  }
 }
 
 const fn default_bool<const V: bool>() -> bool { V }
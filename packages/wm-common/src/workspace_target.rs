@@ -0,0 +1,20 @@
+use anyhow::bail;
+
+/// Parses a keybinding's workspace-index argument (e.g. `focus-workspace
+/// 3`) into the 1-based index expected by `WorkspaceTarget::Index`.
+///
+/// Accepts a signed integer so the keybinding layer can surface a
+/// normal parse error for non-numeric input, then rejects `0` and
+/// anything that doesn't fit in a `u8` with a descriptive message
+/// rather than silently clamping.
+pub fn parse_workspace_index(value: i32) -> anyhow::Result<u8> {
+  if value == 0 {
+    bail!("Workspace index must be 1 or greater, got 0.");
+  }
+
+  u8::try_from(value).map_err(|_| {
+    anyhow::anyhow!(
+      "Workspace index '{value}' is out of range (expected 1-255)."
+    )
+  })
+}
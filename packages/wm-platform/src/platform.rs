@@ -1,39 +1,82 @@
 use std::{
   os::windows::io::AsRawHandle,
   path::{Path, PathBuf},
+  sync::{Mutex, OnceLock},
   thread::JoinHandle,
+  time::Duration,
 };
 
-use anyhow::{bail, Context};
+use anyhow::{anyhow, bail, Context};
 use windows::{
   core::{w, PCWSTR},
   Win32::{
-    Foundation::{HANDLE, HWND, LPARAM, POINT, WPARAM},
+    Foundation::{
+      CloseHandle, COLORREF, HANDLE, HWND, LPARAM, POINT, POINTL, RECT,
+      WPARAM,
+    },
+    Graphics::Gdi::HMONITOR,
     System::{
-      Environment::ExpandEnvironmentStringsW, Threading::GetThreadId,
+      Com::{IDataObject, FORMATETC, TYMED_HGLOBAL},
+      Environment::ExpandEnvironmentStringsW,
+      Ole::{
+        IDropTarget, IDropTarget_Impl, OleInitialize, OleUninitialize,
+        RegisterDragDrop, ReleaseStgMedium, RevokeDragDrop,
+        DROPEFFECT, DROPEFFECT_COPY, DVASPECT_CONTENT, MODIFIERKEYS_FLAGS,
+        CF_HDROP,
+      },
+      Registry::{
+        RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD,
+      },
+      Threading::{
+        GetExitCodeProcess, GetProcessId, GetThreadId, WaitForSingleObject,
+        WAIT_OBJECT_0, WAIT_TIMEOUT,
+      },
     },
     UI::{
+      HiDpi::{
+        GetDpiForMonitor, SetProcessDpiAwarenessContext,
+        DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2, MDT_EFFECTIVE_DPI,
+      },
       Shell::{
-        ShellExecuteExW, SEE_MASK_NOASYNC, SEE_MASK_NOCLOSEPROCESS,
-        SHELLEXECUTEINFOW,
+        DragQueryFileW, ShellExecuteExW, HDROP, SEE_MASK_NOASYNC,
+        SEE_MASK_NOCLOSEPROCESS, SHELLEXECUTEINFOW,
+      },
+      Input::{
+        GetRawInputData, RegisterRawInputDevices,
+        KeyboardAndMouse::{
+          GetAsyncKeyState, RegisterHotKey, UnregisterHotKey, RI_KEY_BREAK,
+          HOT_KEY_MODIFIERS, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT, MOD_SHIFT,
+          MOD_WIN, VK_BACK, VK_DELETE, VK_DOWN, VK_END, VK_ESCAPE, VK_F1,
+          VK_HOME, VK_INSERT, VK_LEFT, VK_MENU, VK_NEXT, VK_OEM_1,
+          VK_OEM_2, VK_OEM_3, VK_OEM_4, VK_OEM_5, VK_OEM_6, VK_OEM_7,
+          VK_OEM_COMMA, VK_OEM_MINUS, VK_OEM_PERIOD, VK_OEM_PLUS, VK_PRIOR,
+          VK_RETURN, VK_RIGHT, VK_SPACE, VK_TAB, VK_UP,
+        },
+        HRAWINPUT, RAWINPUT, RAWINPUTDEVICE, RAWINPUTHEADER, RID_INPUT,
+        RIDEV_INPUTSINK, RIM_TYPEKEYBOARD, RIM_TYPEMOUSE,
       },
-      Input::KeyboardAndMouse::{GetAsyncKeyState, VK_MENU},
       WindowsAndMessaging::{
-        CreateWindowExW, DispatchMessageW, GetAncestor, GetCursorPos,
-        GetDesktopWindow, GetForegroundWindow, GetMessageW,
+        ClipCursor, CreateWindowExW, DestroyWindow, DispatchMessageW,
+        GetAncestor,
+        GetCursorPos, GetDesktopWindow, GetForegroundWindow, GetMessageW,
         GetShellWindow, MessageBoxW, PeekMessageW, PostThreadMessageW,
-        RegisterClassW, SetCursorPos, SystemParametersInfoW,
-        TranslateMessage, WindowFromPoint, ANIMATIONINFO, CS_HREDRAW,
-        CS_VREDRAW, CW_USEDEFAULT, GA_ROOT, MB_ICONERROR, MB_OK,
-        MB_SYSTEMMODAL, MSG, PM_REMOVE, SPIF_SENDCHANGE,
-        SPIF_UPDATEINIFILE, SPI_GETANIMATION, SPI_SETANIMATION, SW_HIDE,
-        SW_NORMAL, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, WINDOW_EX_STYLE,
-        WM_QUIT, WNDCLASSW, WNDPROC, WS_OVERLAPPEDWINDOW,
+        RegisterClassW, SetCursorPos, SetWindowPos, ShowWindow,
+        SystemParametersInfoW, TranslateMessage, WindowFromPoint,
+        ANIMATIONINFO, CS_HREDRAW, CS_VREDRAW, CW_USEDEFAULT, GA_ROOT,
+        HWND_TOPMOST, LWA_ALPHA, MB_ICONERROR, MB_OK, MB_SYSTEMMODAL, MSG,
+        PM_REMOVE, SPIF_SENDCHANGE, SPIF_UPDATEINIFILE,
+        SPI_GETANIMATION, SPI_SETANIMATION, SW_HIDE, SW_NORMAL,
+        SW_SHOWNOACTIVATE, SWP_NOACTIVATE,
+        SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS, SetLayeredWindowAttributes,
+        WINDOW_EX_STYLE, WM_HOTKEY, WM_INPUT, WM_QUIT, WM_SETTINGCHANGE,
+        WNDCLASSW, WNDPROC, WS_EX_LAYERED, WS_EX_NOACTIVATE,
+        WS_EX_TOOLWINDOW, WS_EX_TRANSPARENT, WS_OVERLAPPEDWINDOW,
+        WS_POPUP,
       },
     },
   },
 };
-use wm_common::{ParsedConfig, Point};
+use wm_common::{ParsedConfig, Point, Rect};
 
 use super::{
   native_monitor, native_window, EventListener, NativeMonitor,
@@ -42,6 +85,59 @@ use super::{
 
 pub type WindowProcedure = WNDPROC;
 
+/// A decoded `WM_INPUT` record, returned by `Platform::read_raw_input`.
+pub enum RawInputEvent {
+  /// A `RAWMOUSE` record's relative motion and button transitions.
+  /// `button_flags` is the raw `usButtonFlags` bitmask (e.g.
+  /// `RI_MOUSE_LEFT_BUTTON_DOWN`) - left undecoded since which
+  /// transitions matter is a caller concern.
+  Mouse {
+    delta_x: i32,
+    delta_y: i32,
+    button_flags: u16,
+  },
+  /// A `RAWKEYBOARD` record's make/break code, decoded down to a
+  /// `VK_*` code and whether it's a press (`true`) or release
+  /// (`false`).
+  Keyboard { vk: u16, is_key_down: bool },
+}
+
+/// A running process started by `Platform::run_command`. Holds onto its
+/// process handle/pid so callers can later retrieve its exit code via
+/// `Platform::wait_for_command`, closing the handle on drop.
+///
+/// Both fields are `None` when `ShellExecuteExW` delegated the launch to
+/// an already-running instance via DDE (e.g. opening a file in a
+/// single-instance app) - that's a successful launch with no new process
+/// of its own to track, not a failure, so `wait_for_command` reports a
+/// dedicated error for it instead of callers never seeing a handle-less
+/// success at all.
+pub struct CommandHandle {
+  handle: Option<isize>,
+  pub pid: Option<u32>,
+}
+
+impl Drop for CommandHandle {
+  fn drop(&mut self) {
+    if let Some(handle) = self.handle {
+      _ = unsafe { CloseHandle(HANDLE(handle)) };
+    }
+  }
+}
+
+/// RAII handle returned by `Platform::register_drop_target`. Revokes the
+/// drop target and tears down OLE on `Drop`, so `hwnd`'s drag-and-drop
+/// registration can't outlive the WM without a matching teardown call.
+pub struct DropTargetGuard {
+  hwnd: isize,
+}
+
+impl Drop for DropTargetGuard {
+  fn drop(&mut self) {
+    Platform::revoke_drop_target(self.hwnd);
+  }
+}
+
 pub struct Platform;
 
 impl Platform {
@@ -71,6 +167,278 @@ impl Platform {
     unsafe { GetAsyncKeyState(i32::from(VK_MENU.0)) < 0 }
   }
 
+  /// Registers `accelerator` (see `parse_accelerator`) as a system-wide
+  /// hotkey on `hwnd`, delivered to its window procedure as `WM_HOTKEY`
+  /// with `wparam == id`. This replaces polling modifiers via
+  /// `is_alt_down`/`GetAsyncKeyState` for declarative, config-driven
+  /// keybindings. Each binding needs a stable, process-unique `id` -
+  /// the caller is responsible for picking one (e.g. the binding's
+  /// index in `ParsedConfig.keybindings`) and calling
+  /// `unregister_hotkey` with the same `id` before re-registering it,
+  /// such as on a config reload.
+  pub fn register_hotkey(
+    hwnd: isize,
+    id: i32,
+    accelerator: &str,
+  ) -> anyhow::Result<()> {
+    let parsed = parse_accelerator(accelerator)?;
+
+    unsafe {
+      RegisterHotKey(HWND(hwnd), id, parsed.modifiers, parsed.vk)
+    }
+    .with_context(|| {
+      format!("Failed to register hotkey '{accelerator}'.")
+    })?;
+
+    Ok(())
+  }
+
+  /// Unregisters a hotkey previously registered via `register_hotkey`
+  /// with the same `hwnd`/`id`.
+  pub fn unregister_hotkey(hwnd: isize, id: i32) -> anyhow::Result<()> {
+    unsafe { UnregisterHotKey(HWND(hwnd), id) }
+      .context("Failed to unregister hotkey.")?;
+
+    Ok(())
+  }
+
+  /// Initializes OLE and registers `hwnd` (the `create_message_window`
+  /// HWND) as a drop target, so files/shortcuts dragged from Explorer
+  /// onto a WM surface (e.g. an empty workspace or launcher zone) can
+  /// trigger actions - something `open_file_explorer`/`run_command`
+  /// can't originate on their own. `on_drop` is called with the
+  /// dropped paths and the drop point whenever a drag is released over
+  /// `hwnd`.
+  ///
+  /// Returns a `DropTargetGuard` that calls `revoke_drop_target` on
+  /// `Drop`, mirroring `CommandHandle`'s close-on-drop - so a caller that
+  /// just holds onto the guard for the WM's lifetime can't forget the
+  /// matching teardown call on shutdown.
+  pub fn register_drop_target(
+    hwnd: isize,
+    on_drop: impl Fn(Vec<PathBuf>, Point) + Send + Sync + 'static,
+  ) -> anyhow::Result<DropTargetGuard> {
+    unsafe { OleInitialize(None) }.context("Failed to initialize OLE.")?;
+
+    DROP_CALLBACK
+      .get_or_init(Default::default)
+      .lock()
+      .unwrap()
+      .replace(Box::new(on_drop));
+
+    let drop_target: IDropTarget = FileDropTarget.into();
+
+    unsafe { RegisterDragDrop(HWND(hwnd), &drop_target) }
+      .context("Failed to register drop target.")?;
+
+    Ok(DropTargetGuard { hwnd })
+  }
+
+  /// Unregisters the drop target set up by `register_drop_target` and
+  /// tears down OLE. Called automatically by `DropTargetGuard::drop` -
+  /// only call this directly if the guard was deliberately discarded via
+  /// `std::mem::forget`.
+  pub fn revoke_drop_target(hwnd: isize) {
+    _ = unsafe { RevokeDragDrop(HWND(hwnd)) };
+    DROP_CALLBACK.get_or_init(Default::default).lock().unwrap().take();
+    unsafe { OleUninitialize() };
+  }
+
+  /// Reads whether Windows is currently set to dark mode from
+  /// `HKCU\...\Themes\Personalize\AppsUseLightTheme` (dark when the
+  /// value is `0`). Re-read this whenever
+  /// `is_immersive_color_set_change` recognizes a theme flip, so
+  /// bar/overlay consumers can recolor themselves live instead of only
+  /// checking once at launch.
+  pub fn system_uses_dark_mode() -> anyhow::Result<bool> {
+    let mut value: u32 = 0;
+    let mut value_size = u32::try_from(std::mem::size_of::<u32>())?;
+
+    unsafe {
+      RegGetValueW(
+        HKEY_CURRENT_USER,
+        w!(
+          "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize"
+        ),
+        w!("AppsUseLightTheme"),
+        RRF_RT_REG_DWORD,
+        None,
+        Some(std::ptr::from_mut(&mut value).cast()),
+        Some(&mut value_size),
+      )
+    }
+    .ok()
+    .context("Failed to read AppsUseLightTheme registry value.")?;
+
+    Ok(value == 0)
+  }
+
+  /// Checks whether a `WM_SETTINGCHANGE` message's `lparam` is the
+  /// `"ImmersiveColorSet"` string Windows broadcasts whenever the
+  /// system dark/light theme flips, so the window procedure knows to
+  /// re-read `system_uses_dark_mode` and forward a theme-changed
+  /// notification through `EventListener`.
+  #[must_use]
+  pub fn is_immersive_color_set_change(lparam: isize) -> bool {
+    if lparam == 0 {
+      return false;
+    }
+
+    let setting = unsafe { PCWSTR(lparam as *const u16).to_string() };
+
+    matches!(setting, Ok(setting) if setting == "ImmersiveColorSet")
+  }
+
+  /// Opts the process into per-monitor-v2 DPI awareness, so each
+  /// monitor reports its own effective DPI via `monitor_dpi` instead of
+  /// Windows assuming a single system-wide scale. Call this once at
+  /// startup, before any monitor or window geometry is queried -
+  /// `sorted_monitors`/`nearest_monitor` report raw pixel rects
+  /// regardless, but those rects are only meaningfully comparable
+  /// across mixed-DPI monitors once the process has opted in.
+  pub fn set_process_dpi_awareness() -> anyhow::Result<()> {
+    unsafe {
+      SetProcessDpiAwarenessContext(
+        DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+      )
+    }
+    .context("Failed to set process DPI awareness.")?;
+
+    Ok(())
+  }
+
+  /// Gets the effective DPI of the monitor at `monitor_handle` (an
+  /// `HMONITOR`), as reported by `GetDpiForMonitor(MDT_EFFECTIVE_DPI)`.
+  /// 96 is Windows' un-scaled baseline DPI; pass the result through
+  /// `monitor_scale_factor` to get a `1.0`-based multiplier.
+  pub fn monitor_dpi(monitor_handle: isize) -> anyhow::Result<u32> {
+    let mut dpi_x = 0_u32;
+    let mut dpi_y = 0_u32;
+
+    unsafe {
+      GetDpiForMonitor(
+        HMONITOR(monitor_handle),
+        MDT_EFFECTIVE_DPI,
+        &mut dpi_x,
+        &mut dpi_y,
+      )
+    }
+    .context("Failed to get monitor DPI.")?;
+
+    Ok(dpi_x)
+  }
+
+  /// Converts a raw DPI value (from `monitor_dpi`) into a `1.0`-based
+  /// scale factor (e.g. `1.5` at 144 DPI), for converting logical to
+  /// physical coordinates when placing windows across mixed-DPI
+  /// monitors.
+  #[must_use]
+  #[allow(clippy::cast_precision_loss)]
+  pub fn monitor_scale_factor(dpi: u32) -> f32 {
+    dpi as f32 / 96.0
+  }
+
+  /// Subscribes `hwnd` (the `create_message_window` HWND) to Raw Input
+  /// for the generic-desktop mouse and keyboard usages, with
+  /// `RIDEV_INPUTSINK` so events keep arriving as `WM_INPUT` even while
+  /// the WM isn't the foreground app. This replaces polling
+  /// `GetCursorPos`/`GetAsyncKeyState` every tick with event-driven
+  /// delivery, which matters for focus-follows-cursor and drag-to-swap
+  /// gestures at high pointer-report rates. Pair with `read_raw_input`
+  /// in the window procedure's `WM_INPUT` handler.
+  pub fn register_raw_input_devices(hwnd: isize) -> anyhow::Result<()> {
+    let devices = [
+      RAWINPUTDEVICE {
+        usUsagePage: 0x01,
+        usUsage: 0x02,
+        dwFlags: RIDEV_INPUTSINK,
+        hwndTarget: HWND(hwnd),
+      },
+      RAWINPUTDEVICE {
+        usUsagePage: 0x01,
+        usUsage: 0x06,
+        dwFlags: RIDEV_INPUTSINK,
+        hwndTarget: HWND(hwnd),
+      },
+    ];
+
+    let succeeded = unsafe {
+      RegisterRawInputDevices(
+        &devices,
+        u32::try_from(std::mem::size_of::<RAWINPUTDEVICE>())?,
+      )
+    }
+    .as_bool();
+
+    if !succeeded {
+      bail!("Failed to register raw input devices.");
+    }
+
+    Ok(())
+  }
+
+  /// Decodes a `WM_INPUT` message's `lparam` into a `RawInputEvent` via
+  /// `GetRawInputData`, first querying the required buffer size and
+  /// then filling it. Returns `Ok(None)` for a Raw Input type this WM
+  /// doesn't act on (anything besides mouse/keyboard).
+  pub fn read_raw_input(
+    lparam: isize,
+  ) -> anyhow::Result<Option<RawInputEvent>> {
+    let handle = HRAWINPUT(lparam);
+    let header_size = u32::try_from(std::mem::size_of::<RAWINPUTHEADER>())?;
+    let mut size: u32 = 0;
+
+    unsafe {
+      GetRawInputData(handle, RID_INPUT, None, &mut size, header_size);
+    }
+
+    if size == 0 {
+      return Ok(None);
+    }
+
+    let mut buffer = vec![0_u8; size as usize];
+
+    let bytes_written = unsafe {
+      GetRawInputData(
+        handle,
+        RID_INPUT,
+        Some(buffer.as_mut_ptr().cast()),
+        &mut size,
+        header_size,
+      )
+    };
+
+    if bytes_written == u32::MAX || bytes_written as usize != buffer.len() {
+      bail!("Failed to read raw input data.");
+    }
+
+    // SAFETY: `buffer` was sized and filled by `GetRawInputData` to
+    // hold exactly one `RAWINPUT` record.
+    let raw_input = unsafe { &*buffer.as_ptr().cast::<RAWINPUT>() };
+
+    let event = if raw_input.header.dwType == RIM_TYPEMOUSE.0 {
+      let mouse = unsafe { raw_input.data.mouse };
+      let button_flags = unsafe { mouse.Anonymous.Anonymous.usButtonFlags };
+
+      Some(RawInputEvent::Mouse {
+        delta_x: mouse.lLastX,
+        delta_y: mouse.lLastY,
+        button_flags,
+      })
+    } else if raw_input.header.dwType == RIM_TYPEKEYBOARD.0 {
+      let keyboard = unsafe { raw_input.data.keyboard };
+
+      Some(RawInputEvent::Keyboard {
+        vk: keyboard.VKey,
+        is_key_down: keyboard.Flags & RI_KEY_BREAK as u16 == 0,
+      })
+    } else {
+      None
+    };
+
+    Ok(event)
+  }
+
   /// Gets a vector of available monitors as `NativeMonitor` instances
   /// sorted from left-to-right and top-to-bottom.
   pub fn sorted_monitors() -> anyhow::Result<Vec<NativeMonitor>> {
@@ -195,11 +563,285 @@ impl Platform {
     Ok(handle.0)
   }
 
+  /// Creates a borderless, click-through, always-on-top layered window
+  /// used to draw a custom border overlay around a managed window.
+  /// Painting is entirely driven by `window_procedure` (expected to
+  /// handle `WM_PAINT` itself), since DWM's own accent border
+  /// (`NativeWindow::set_border_color`) is limited to a single pixel and
+  /// unavailable pre-Windows 11.
+  pub fn create_border_overlay_window(
+    window_procedure: WindowProcedure,
+  ) -> anyhow::Result<isize> {
+    let wnd_class = WNDCLASSW {
+      lpszClassName: w!("WmBorderOverlay"),
+      style: CS_HREDRAW | CS_VREDRAW,
+      lpfnWndProc: window_procedure,
+      ..Default::default()
+    };
+
+    unsafe { RegisterClassW(&raw const wnd_class) };
+
+    let handle = unsafe {
+      CreateWindowExW(
+        WS_EX_LAYERED
+          | WS_EX_TRANSPARENT
+          | WS_EX_NOACTIVATE
+          | WS_EX_TOOLWINDOW,
+        w!("WmBorderOverlay"),
+        w!("WmBorderOverlay"),
+        WS_POPUP,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        None,
+        None,
+        wnd_class.hInstance,
+        None,
+      )
+    };
+
+    if handle.0 == 0 {
+      bail!("Creation of border overlay window failed.");
+    }
+
+    // A `WS_EX_LAYERED` window paints fully transparent until its
+    // layered attributes are set at least once - without this, the
+    // `FrameRect` border painted in `window_procedure` would never
+    // actually be visible.
+    unsafe {
+      SetLayeredWindowAttributes(handle, COLORREF::default(), 255, LWA_ALPHA)
+    }
+    .context("Failed to set border overlay opacity.")?;
+
+    Ok(handle.0)
+  }
+
+  /// Moves `overlay_handle` to frame `rect` and, when `after_handle` is
+  /// given, inserts it directly above that window in z-order (mirroring
+  /// `NativeWindow::set_z_order`'s `ZOrder::AfterWindow`) so the border
+  /// stays glued just above its target window but below everything
+  /// else. With no `after_handle`, the overlay is pinned topmost.
+  #[allow(clippy::too_many_arguments)]
+  pub fn position_border_overlay(
+    overlay_handle: isize,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    after_handle: Option<isize>,
+    is_visible: bool,
+  ) -> anyhow::Result<()> {
+    let insert_after = after_handle.map_or(HWND_TOPMOST, HWND);
+
+    unsafe {
+      SetWindowPos(
+        HWND(overlay_handle),
+        insert_after,
+        x,
+        y,
+        width,
+        height,
+        SWP_NOACTIVATE,
+      )
+    }
+    .context("Failed to reposition border overlay.")?;
+
+    unsafe {
+      ShowWindow(
+        HWND(overlay_handle),
+        if is_visible { SW_SHOWNOACTIVATE } else { SW_HIDE },
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Tears down a border overlay created via `create_border_overlay_window`,
+  /// e.g. once its target window is unmanaged.
+  pub fn destroy_border_overlay(overlay_handle: isize) {
+    _ = unsafe { DestroyWindow(HWND(overlay_handle)) };
+  }
+
+  /// Clips the cursor to `rect` (e.g. the union of monitor work areas)
+  /// so it can't wander onto an unmanaged area mid-drag and lose
+  /// capture. Pair with `release_cursor_confinement` on mouse-up.
+  pub fn confine_cursor(rect: &Rect) -> anyhow::Result<()> {
+    let clip_rect = RECT {
+      left: rect.left,
+      top: rect.top,
+      right: rect.right,
+      bottom: rect.bottom,
+    };
+
+    unsafe { ClipCursor(Some(&raw const clip_rect)) }
+      .context("Failed to confine cursor.")?;
+
+    Ok(())
+  }
+
+  /// Releases a clip set by `confine_cursor`, restoring the cursor's
+  /// freedom to move across all monitors.
+  pub fn release_cursor_confinement() {
+    _ = unsafe { ClipCursor(None) };
+  }
+
+  /// Creates a borderless, click-through, always-on-top layered window
+  /// used to show the workspace-switch OSD. Like
+  /// `create_border_overlay_window`, painting is entirely driven by
+  /// `window_procedure`; `set_osd_overlay_opacity` additionally drives
+  /// the layered alpha for the OSD's fade-out.
+  pub fn create_osd_overlay_window(
+    window_procedure: WindowProcedure,
+  ) -> anyhow::Result<isize> {
+    let wnd_class = WNDCLASSW {
+      lpszClassName: w!("WmWorkspaceOsd"),
+      style: CS_HREDRAW | CS_VREDRAW,
+      lpfnWndProc: window_procedure,
+      ..Default::default()
+    };
+
+    unsafe { RegisterClassW(&raw const wnd_class) };
+
+    let handle = unsafe {
+      CreateWindowExW(
+        WS_EX_LAYERED
+          | WS_EX_TRANSPARENT
+          | WS_EX_NOACTIVATE
+          | WS_EX_TOOLWINDOW,
+        w!("WmWorkspaceOsd"),
+        w!("WmWorkspaceOsd"),
+        WS_POPUP,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        None,
+        None,
+        wnd_class.hInstance,
+        None,
+      )
+    };
+
+    if handle.0 == 0 {
+      bail!("Creation of workspace OSD overlay window failed.");
+    }
+
+    Ok(handle.0)
+  }
+
+  /// Moves the OSD overlay to `x`/`y`/`width`/`height` (already resolved
+  /// against the target monitor and `WorkspaceOsdConfig.position`) and
+  /// shows or hides it, pinned topmost.
+  pub fn position_osd_overlay(
+    overlay_handle: isize,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    is_visible: bool,
+  ) -> anyhow::Result<()> {
+    unsafe {
+      SetWindowPos(
+        HWND(overlay_handle),
+        HWND_TOPMOST,
+        x,
+        y,
+        width,
+        height,
+        SWP_NOACTIVATE,
+      )
+    }
+    .context("Failed to reposition workspace OSD overlay.")?;
+
+    unsafe {
+      ShowWindow(
+        HWND(overlay_handle),
+        if is_visible { SW_SHOWNOACTIVATE } else { SW_HIDE },
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Sets the OSD overlay's layered-window alpha, used to fade it out
+  /// once `WorkspaceOsdConfig.timeout_ms` elapses.
+  pub fn set_osd_overlay_opacity(
+    overlay_handle: isize,
+    alpha: u8,
+  ) -> anyhow::Result<()> {
+    unsafe {
+      SetLayeredWindowAttributes(
+        HWND(overlay_handle),
+        COLORREF::default(),
+        alpha,
+        LWA_ALPHA,
+      )
+    }
+    .context("Failed to set workspace OSD overlay opacity.")?;
+
+    Ok(())
+  }
+
+  /// Tears down an OSD overlay created via `create_osd_overlay_window`.
+  pub fn destroy_osd_overlay(overlay_handle: isize) {
+    _ = unsafe { DestroyWindow(HWND(overlay_handle)) };
+  }
+
+  /// Installs the callback invoked whenever the message loop
+  /// (`run_message_loop`/`run_message_cycle`) sees a `WM_HOTKEY` for a
+  /// hotkey registered via `register_hotkey` - `wparam` is the same `id`
+  /// the hotkey was registered with. Replaces any previously installed
+  /// callback each time it's set. The callback lives in a static,
+  /// mirroring `register_drop_target`'s `DROP_CALLBACK` stash, since the
+  /// message loop functions take no parameter to carry a closure through.
+  pub fn set_hotkey_handler(
+    callback: impl Fn(i32) + Send + Sync + 'static,
+  ) {
+    HOTKEY_CALLBACK
+      .get_or_init(Default::default)
+      .lock()
+      .unwrap()
+      .replace(Box::new(callback));
+  }
+
+  /// Installs the callback invoked whenever the message loop decodes a
+  /// `WM_INPUT` message arriving at a window registered via
+  /// `register_raw_input_devices`. See `set_hotkey_handler` for why this
+  /// is a static callback rather than a loop parameter.
+  pub fn set_raw_input_handler(
+    callback: impl Fn(RawInputEvent) + Send + Sync + 'static,
+  ) {
+    RAW_INPUT_CALLBACK
+      .get_or_init(Default::default)
+      .lock()
+      .unwrap()
+      .replace(Box::new(callback));
+  }
+
+  /// Installs the callback invoked whenever the message loop sees the
+  /// `WM_SETTINGCHANGE` Windows broadcasts for a dark/light theme flip.
+  /// See `set_hotkey_handler` for why this is a static callback rather
+  /// than a loop parameter.
+  pub fn set_theme_change_handler(
+    callback: impl Fn(bool) + Send + Sync + 'static,
+  ) {
+    THEME_CHANGE_CALLBACK
+      .get_or_init(Default::default)
+      .lock()
+      .unwrap()
+      .replace(Box::new(callback));
+  }
+
   pub fn run_message_loop() {
     let mut msg = MSG::default();
 
     loop {
       if unsafe { GetMessageW(&raw mut msg, None, 0, 0) }.as_bool() {
+        dispatch_hotkey_message(&msg);
+        dispatch_raw_input_message(&msg);
+        dispatch_theme_change_message(&msg);
+
         unsafe {
           TranslateMessage(&raw const msg);
           DispatchMessageW(&raw const msg);
@@ -222,6 +864,10 @@ impl Platform {
         bail!("Received WM_QUIT message.")
       }
 
+      dispatch_hotkey_message(&msg);
+      dispatch_raw_input_message(&msg);
+      dispatch_theme_change_message(&msg);
+
       unsafe {
         TranslateMessage(&raw const msg);
         DispatchMessageW(&raw const msg);
@@ -363,11 +1009,16 @@ impl Platform {
     anyhow::bail!("Program path is not valid for command '{}'.", command)
   }
 
+  /// Launches `program` via `ShellExecuteExW` and returns a
+  /// `CommandHandle` wrapping its process handle/pid, so callers can
+  /// tell whether it actually launched and later retrieve its exit
+  /// code with `wait_for_command` - rather than assuming success the
+  /// moment `ShellExecuteExW` returns.
   pub fn run_command(
     program: &str,
     args: &str,
     hide_window: bool,
-  ) -> anyhow::Result<()> {
+  ) -> anyhow::Result<CommandHandle> {
     let home_dir = home::home_dir()
       .context("Unable to get home directory.")?
       .to_str()
@@ -390,7 +1041,76 @@ impl Platform {
     };
 
     unsafe { ShellExecuteExW(&raw mut exec_info) }?;
-    Ok(())
+
+    // On failure, `hInstApp` holds an SE_ERR_* classification code
+    // (<= 32) instead of the instance handle `ShellExecuteW` returns -
+    // `ShellExecuteExW` itself doesn't surface that distinction as an
+    // `Err`, so it has to be read out manually to give config-driven
+    // launch commands an actionable error instead of a generic one.
+    let hinstapp = exec_info.hInstApp.0;
+
+    if hinstapp <= 32 {
+      return Err(shell_execute_error(hinstapp, program));
+    }
+
+    // A valid `hInstApp` with an invalid `hProcess` means the shell
+    // delegated the launch to an existing process via DDE rather than
+    // starting a new one (common for single-instance file associations) -
+    // that's still a successful launch, just with nothing to wait on.
+    if exec_info.hProcess.is_invalid() {
+      return Ok(CommandHandle {
+        handle: None,
+        pid: None,
+      });
+    }
+
+    let pid = unsafe { GetProcessId(exec_info.hProcess) };
+
+    Ok(CommandHandle {
+      handle: Some(exec_info.hProcess.0),
+      pid: Some(pid),
+    })
+  }
+
+  /// Waits up to `timeout` for a command started by `run_command` to
+  /// exit, then returns its exit code via `GetExitCodeProcess` -
+  /// mirroring how windowing event loops thread an explicit exit code
+  /// out of their run function, rather than callers having to assume
+  /// success once the process launches.
+  pub fn wait_for_command(
+    handle: &CommandHandle,
+    timeout: Duration,
+  ) -> anyhow::Result<u32> {
+    let Some(raw_handle) = handle.handle else {
+      bail!(
+        "Command was delegated to an existing instance via DDE; there's \
+         no process handle to wait on."
+      );
+    };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let timeout_ms = timeout.as_millis() as u32;
+
+    let wait_result =
+      unsafe { WaitForSingleObject(HANDLE(raw_handle), timeout_ms) };
+
+    if wait_result == WAIT_TIMEOUT {
+      bail!(
+        "Command (pid {:?}) did not exit within the timeout.",
+        handle.pid
+      );
+    }
+
+    if wait_result != WAIT_OBJECT_0 {
+      bail!("Failed to wait on command (pid {:?}).", handle.pid);
+    }
+
+    let mut exit_code = 0_u32;
+
+    unsafe { GetExitCodeProcess(HANDLE(raw_handle), &mut exit_code) }
+      .context("Failed to get command exit code.")?;
+
+    Ok(exit_code)
   }
 
   pub fn show_error_dialog(title: &str, message: &str) {
@@ -410,4 +1130,322 @@ impl Platform {
 
 fn to_wide(string: &str) -> Vec<u16> {
   string.encode_utf16().chain(Some(0)).collect()
+}
+
+/// Parsed form of a `KeybindingConfig` accelerator string, ready to
+/// pass to `RegisterHotKey`.
+struct ParsedAccelerator {
+  modifiers: HOT_KEY_MODIFIERS,
+  vk: u32,
+}
+
+/// Parses an accelerator string (e.g. `"Alt+Shift+1"`,
+/// `"Super+Enter"`, `"Ctrl+F13"`) into Win32 modifier flags and a
+/// virtual-key code. Splits on `+`; every token but the last must be a
+/// modifier (`Ctrl`/`Alt`/`Shift`/`Super`, case-insensitive) and the
+/// last token is the key itself. `MOD_NOREPEAT` is always added so a
+/// held key doesn't keep re-firing `WM_HOTKEY`. Returns a descriptive
+/// error on an unrecognized token rather than silently dropping the
+/// binding.
+fn parse_accelerator(accelerator: &str) -> anyhow::Result<ParsedAccelerator> {
+  let tokens = accelerator
+    .split('+')
+    .map(str::trim)
+    .filter(|token| !token.is_empty())
+    .collect::<Vec<_>>();
+
+  let Some((key_token, modifier_tokens)) = tokens.split_last() else {
+    bail!("Accelerator string '{}' is empty.", accelerator);
+  };
+
+  let mut modifiers = MOD_NOREPEAT;
+
+  for token in modifier_tokens {
+    modifiers |= match token.to_lowercase().as_str() {
+      "ctrl" | "control" => MOD_CONTROL,
+      "alt" => MOD_ALT,
+      "shift" => MOD_SHIFT,
+      "super" | "win" | "windows" => MOD_WIN,
+      _ => bail!(
+        "Unrecognized modifier '{}' in accelerator '{}'.",
+        token,
+        accelerator
+      ),
+    };
+  }
+
+  let vk = vk_from_token(key_token).with_context(|| {
+    format!("Unrecognized key '{key_token}' in accelerator '{accelerator}'.")
+  })?;
+
+  Ok(ParsedAccelerator {
+    modifiers,
+    vk: u32::from(vk),
+  })
+}
+
+/// Maps a single accelerator key token to its `VK_*` code, covering
+/// digits, letters, `F1`-`F24`, the named keys, and the full US
+/// punctuation set (`` ` ``, `-`, `=`, `[`, `]`, `\`, `;`, `'`, `,`,
+/// `.`, `/`).
+fn vk_from_token(token: &str) -> anyhow::Result<u16> {
+  if let Some(vk) = match token.to_lowercase().as_str() {
+    "space" => Some(VK_SPACE.0),
+    "tab" => Some(VK_TAB.0),
+    "enter" | "return" => Some(VK_RETURN.0),
+    "escape" | "esc" => Some(VK_ESCAPE.0),
+    "backspace" => Some(VK_BACK.0),
+    "delete" | "del" => Some(VK_DELETE.0),
+    "insert" | "ins" => Some(VK_INSERT.0),
+    "home" => Some(VK_HOME.0),
+    "end" => Some(VK_END.0),
+    "pageup" | "prior" => Some(VK_PRIOR.0),
+    "pagedown" | "next" => Some(VK_NEXT.0),
+    "up" => Some(VK_UP.0),
+    "down" => Some(VK_DOWN.0),
+    "left" => Some(VK_LEFT.0),
+    "right" => Some(VK_RIGHT.0),
+    "-" => Some(VK_OEM_MINUS.0),
+    "=" => Some(VK_OEM_PLUS.0),
+    "," => Some(VK_OEM_COMMA.0),
+    "." => Some(VK_OEM_PERIOD.0),
+    ";" => Some(VK_OEM_1.0),
+    "/" => Some(VK_OEM_2.0),
+    "`" => Some(VK_OEM_3.0),
+    "[" => Some(VK_OEM_4.0),
+    "\\" => Some(VK_OEM_5.0),
+    "]" => Some(VK_OEM_6.0),
+    "'" => Some(VK_OEM_7.0),
+    _ => None,
+  } {
+    return Ok(vk);
+  }
+
+  if token.len() == 1 {
+    let ch = token.chars().next().context("Empty key token.")?;
+
+    if ch.is_ascii_digit() || ch.is_ascii_alphabetic() {
+      return Ok(ch.to_ascii_uppercase() as u16);
+    }
+  }
+
+  if let Some(fn_number) = token.to_lowercase().strip_prefix('f') {
+    if let Ok(n) = fn_number.parse::<u16>() {
+      if (1..=24).contains(&n) {
+        return Ok(VK_F1.0 + (n - 1));
+      }
+    }
+  }
+
+  bail!("Unrecognized key token '{}'.", token)
+}
+
+/// Callback registered via `Platform::register_drop_target`, invoked
+/// from `FileDropTarget::Drop`. A bare COM vtable method has no way to
+/// capture state, so the callback is stashed here instead (mirroring
+/// `border_overlay::OVERLAY_STYLES`'s global-lookup pattern).
+static DROP_CALLBACK: OnceLock<
+  Mutex<Option<Box<dyn Fn(Vec<PathBuf>, Point) + Send + Sync>>>,
+> = OnceLock::new();
+
+/// Callback installed via `Platform::set_hotkey_handler`, invoked by
+/// `dispatch_hotkey_message` for every `WM_HOTKEY` the message loop sees.
+static HOTKEY_CALLBACK: OnceLock<Mutex<Option<Box<dyn Fn(i32) + Send + Sync>>>> =
+  OnceLock::new();
+
+/// Callback installed via `Platform::set_raw_input_handler`, invoked by
+/// `dispatch_raw_input_message` for every decoded `WM_INPUT` record.
+static RAW_INPUT_CALLBACK: OnceLock<
+  Mutex<Option<Box<dyn Fn(RawInputEvent) + Send + Sync>>>,
+> = OnceLock::new();
+
+/// Callback installed via `Platform::set_theme_change_handler`, invoked
+/// by `dispatch_theme_change_message` with the freshly re-read
+/// `system_uses_dark_mode` value whenever the system theme flips.
+static THEME_CHANGE_CALLBACK: OnceLock<
+  Mutex<Option<Box<dyn Fn(bool) + Send + Sync>>>,
+> = OnceLock::new();
+
+/// Forwards a `WM_HOTKEY` message's `wparam` (the hotkey `id` passed to
+/// `register_hotkey`) to `HOTKEY_CALLBACK`, if one is installed. Called
+/// from both `run_message_loop` and `run_message_cycle` before the
+/// message is translated/dispatched, so a hotkey reaches the callback
+/// without the window procedure passed to `create_message_window`
+/// having to handle `WM_HOTKEY` itself.
+fn dispatch_hotkey_message(msg: &MSG) {
+  if msg.message != WM_HOTKEY {
+    return;
+  }
+
+  #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+  let id = msg.wParam.0 as i32;
+
+  if let Some(callback) =
+    HOTKEY_CALLBACK.get_or_init(Default::default).lock().unwrap().as_ref()
+  {
+    callback(id);
+  }
+}
+
+/// Decodes a `WM_INPUT` message via `Platform::read_raw_input` and
+/// forwards the result to `RAW_INPUT_CALLBACK`, if one is installed and
+/// the record decoded to a mouse/keyboard event this WM acts on. Called
+/// from both `run_message_loop` and `run_message_cycle` alongside
+/// `dispatch_hotkey_message`.
+fn dispatch_raw_input_message(msg: &MSG) {
+  if msg.message != WM_INPUT {
+    return;
+  }
+
+  let Ok(Some(event)) = Platform::read_raw_input(msg.lParam.0) else {
+    return;
+  };
+
+  if let Some(callback) = RAW_INPUT_CALLBACK
+    .get_or_init(Default::default)
+    .lock()
+    .unwrap()
+    .as_ref()
+  {
+    callback(event);
+  }
+}
+
+/// Re-reads `Platform::system_uses_dark_mode` and forwards it to
+/// `THEME_CHANGE_CALLBACK` when `msg` is the `WM_SETTINGCHANGE` Windows
+/// broadcasts for a theme flip (see
+/// `Platform::is_immersive_color_set_change`). Called from both
+/// `run_message_loop` and `run_message_cycle`.
+fn dispatch_theme_change_message(msg: &MSG) {
+  if msg.message != WM_SETTINGCHANGE
+    || !Platform::is_immersive_color_set_change(msg.lParam.0)
+  {
+    return;
+  }
+
+  let Ok(uses_dark_mode) = Platform::system_uses_dark_mode() else {
+    return;
+  };
+
+  if let Some(callback) = THEME_CHANGE_CALLBACK
+    .get_or_init(Default::default)
+    .lock()
+    .unwrap()
+    .as_ref()
+  {
+    callback(uses_dark_mode);
+  }
+}
+
+/// Minimal `IDropTarget` COM implementation backing
+/// `Platform::register_drop_target`. Accepts every drag (always
+/// reports `DROPEFFECT_COPY`) and, on `Drop`, resolves the dropped file
+/// paths via `DragQueryFileW` and forwards them plus the drop point to
+/// `DROP_CALLBACK`.
+#[windows::core::implement(IDropTarget)]
+struct FileDropTarget;
+
+impl IDropTarget_Impl for FileDropTarget {
+  fn DragEnter(
+    &self,
+    _pdataobj: Option<&IDataObject>,
+    _grfkeystate: MODIFIERKEYS_FLAGS,
+    _pt: &POINTL,
+    pdweffect: *mut DROPEFFECT,
+  ) -> windows::core::Result<()> {
+    unsafe { *pdweffect = DROPEFFECT_COPY };
+    Ok(())
+  }
+
+  fn DragOver(
+    &self,
+    _grfkeystate: MODIFIERKEYS_FLAGS,
+    _pt: &POINTL,
+    pdweffect: *mut DROPEFFECT,
+  ) -> windows::core::Result<()> {
+    unsafe { *pdweffect = DROPEFFECT_COPY };
+    Ok(())
+  }
+
+  fn DragLeave(&self) -> windows::core::Result<()> {
+    Ok(())
+  }
+
+  fn Drop(
+    &self,
+    pdataobj: Option<&IDataObject>,
+    _grfkeystate: MODIFIERKEYS_FLAGS,
+    pt: &POINTL,
+    pdweffect: *mut DROPEFFECT,
+  ) -> windows::core::Result<()> {
+    unsafe { *pdweffect = DROPEFFECT_COPY };
+
+    let Some(data_object) = pdataobj else {
+      return Ok(());
+    };
+
+    let paths = dropped_file_paths(data_object).unwrap_or_default();
+    let drop_point = Point { x: pt.x, y: pt.y };
+
+    if let Some(callback) =
+      DROP_CALLBACK.get_or_init(Default::default).lock().unwrap().as_ref()
+    {
+      callback(paths, drop_point);
+    }
+
+    Ok(())
+  }
+}
+
+/// Classifies one of the `SE_ERR_*` pseudo-handle codes `ShellExecuteExW`
+/// leaves in `hInstApp` (a value `<= 32`) into a descriptive error,
+/// since `ShellExecuteExW` itself only reports whether the call
+/// succeeded, not why the launch it "succeeded" at actually failed.
+fn shell_execute_error(hinstapp: isize, program: &str) -> anyhow::Error {
+  match hinstapp {
+    2 => anyhow!("'{}' not found.", program),
+    3 => anyhow!("Path for '{}' not found.", program),
+    5 | 31 => anyhow!("Access denied launching '{}'.", program),
+    8 => anyhow!("Out of memory launching '{}'.", program),
+    26 => anyhow!("Sharing violation launching '{}'.", program),
+    code => anyhow!("Failed to launch '{}' (error code {}).", program, code),
+  }
+}
+
+/// Enumerates the file paths carried by a dropped `IDataObject` -
+/// resolves its `CF_HDROP` clipboard-format data and walks it with
+/// `DragQueryFileW`.
+fn dropped_file_paths(
+  data_object: &IDataObject,
+) -> anyhow::Result<Vec<PathBuf>> {
+  let format = FORMATETC {
+    cfFormat: CF_HDROP.0 as u16,
+    ptd: std::ptr::null_mut(),
+    dwAspect: DVASPECT_CONTENT.0,
+    lindex: -1,
+    tymed: TYMED_HGLOBAL.0 as u32,
+  };
+
+  let mut medium = unsafe { data_object.GetData(&format) }
+    .context("Failed to get dropped file data.")?;
+
+  let hdrop = HDROP(unsafe { medium.u.hGlobal.0 } as isize);
+
+  let file_count = unsafe { DragQueryFileW(hdrop, u32::MAX, None) };
+  let mut paths = Vec::with_capacity(file_count as usize);
+
+  for index in 0..file_count {
+    let len = unsafe { DragQueryFileW(hdrop, index, None) };
+    let mut buffer = vec![0_u16; len as usize + 1];
+
+    unsafe { DragQueryFileW(hdrop, index, Some(&mut buffer)) };
+
+    paths.push(PathBuf::from(String::from_utf16_lossy(
+      &buffer[..len as usize],
+    )));
+  }
+
+  unsafe { ReleaseStgMedium(&mut medium) };
+
+  Ok(paths)
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use std::{
+  collections::HashMap,
+  sync::{Mutex, OnceLock},
+};
+
+use wm_common::Color;
+use windows::Win32::{
+  Foundation::{COLORREF, HWND, LPARAM, LRESULT, RECT, WPARAM},
+  Graphics::Gdi::{
+    BeginPaint, CreateSolidBrush, DeleteObject, EndPaint, FrameRect,
+    PAINTSTRUCT,
+  },
+  UI::WindowsAndMessaging::{DefWindowProcW, GetClientRect, WM_PAINT},
+};
+use wm_platform::Platform;
+
+/// A custom-drawn topmost overlay window tracking a single managed
+/// window, used in place of (or alongside) DWM's 1px accent border so
+/// users can get thick, styleable borders on Windows 10 too. See
+/// `apply_border_effect` in `platform_sync` for how these are driven.
+pub struct BorderOverlay {
+  pub handle: isize,
+
+  /// Inset applied to whatever rect `reposition_border_overlay` is given,
+  /// so `redraw_containers` can keep the overlay glued to the window on
+  /// every move/resize without needing the full `WindowEffectConfig`.
+  pub border_offset: i32,
+}
+
+impl Drop for BorderOverlay {
+  fn drop(&mut self) {
+    OVERLAY_STYLES.get_or_init(Default::default).lock().unwrap().remove(&self.handle);
+    Platform::destroy_border_overlay(self.handle);
+  }
+}
+
+/// Color + thickness currently painted by each overlay, keyed by the
+/// overlay's own window handle. The `WNDPROC` below is a bare function
+/// pointer with no way to capture state, so its `WM_PAINT` handler looks
+/// style up here instead.
+static OVERLAY_STYLES: OnceLock<Mutex<HashMap<isize, (Color, i32)>>> =
+  OnceLock::new();
+
+/// Creates a new border overlay window painted with `color` at
+/// `thickness` pixels. Call `reposition_border_overlay` afterwards to
+/// place and show it; a freshly created overlay starts out hidden.
+pub fn create_border_overlay(
+  color: Color,
+  thickness: i32,
+  border_offset: i32,
+) -> anyhow::Result<BorderOverlay> {
+  let handle =
+    Platform::create_border_overlay_window(Some(border_overlay_wndproc))?;
+
+  OVERLAY_STYLES
+    .get_or_init(Default::default)
+    .lock()
+    .unwrap()
+    .insert(handle, (color, thickness));
+
+  Ok(BorderOverlay {
+    handle,
+    border_offset,
+  })
+}
+
+/// Repositions `overlay` to hug the *visible* edge of `target_rect`,
+/// insetting each side by `overlay.border_offset` to compensate for
+/// Win32's invisible resize-border region. When `after_handle` is given,
+/// the overlay is inserted directly above that window in z-order
+/// (mirroring `redraw_containers`' `ZOrder::AfterWindow` logic) so it
+/// stays glued just above its target but below whatever else is on top.
+pub fn reposition_border_overlay(
+  overlay: &BorderOverlay,
+  target_x: i32,
+  target_y: i32,
+  target_width: i32,
+  target_height: i32,
+  after_handle: Option<isize>,
+  is_visible: bool,
+) -> anyhow::Result<()> {
+  let offset = overlay.border_offset;
+
+  Platform::position_border_overlay(
+    overlay.handle,
+    target_x + offset,
+    target_y + offset,
+    (target_width - offset * 2).max(0),
+    (target_height - offset * 2).max(0),
+    after_handle,
+    is_visible,
+  )
+}
+
+/// Updates the color/thickness an overlay paints on its next `WM_PAINT`,
+/// used when a window transitions between focused and unfocused state.
+pub fn set_border_overlay_style(
+  overlay: &BorderOverlay,
+  color: Color,
+  thickness: i32,
+) {
+  OVERLAY_STYLES
+    .get_or_init(Default::default)
+    .lock()
+    .unwrap()
+    .insert(overlay.handle, (color, thickness));
+}
+
+unsafe extern "system" fn border_overlay_wndproc(
+  handle: HWND,
+  message: u32,
+  wparam: WPARAM,
+  lparam: LPARAM,
+) -> LRESULT {
+  if message == WM_PAINT {
+    paint_border_overlay(handle);
+    return LRESULT(0);
+  }
+
+  DefWindowProcW(handle, message, wparam, lparam)
+}
+
+fn paint_border_overlay(handle: HWND) {
+  let style = OVERLAY_STYLES
+    .get_or_init(Default::default)
+    .lock()
+    .unwrap()
+    .get(&handle.0)
+    .cloned();
+
+  let Some((color, thickness)) = style else {
+    return;
+  };
+
+  unsafe {
+    let mut paint_struct = PAINTSTRUCT::default();
+    let hdc = BeginPaint(handle, &mut paint_struct);
+
+    let mut client_rect = RECT::default();
+    _ = GetClientRect(handle, &mut client_rect);
+
+    let brush = CreateSolidBrush(COLORREF(
+      u32::from(color.b) << 16 | u32::from(color.g) << 8 | u32::from(color.r),
+    ));
+
+    for _ in 0..thickness.max(1) {
+      FrameRect(hdc, &client_rect, brush);
+      client_rect.left += 1;
+      client_rect.top += 1;
+      client_rect.right -= 1;
+      client_rect.bottom -= 1;
+    }
+
+    _ = DeleteObject(brush);
+    _ = EndPaint(handle, &paint_struct);
+  }
+}
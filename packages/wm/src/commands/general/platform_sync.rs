@@ -1,24 +1,28 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use anyhow::Context;
 use tokio::task;
 use tracing::{info, warn};
 use wm_common::{
-  CornerStyle, CursorJumpTrigger, DisplayState, HideMethod, OpacityValue,
-  UniqueExt, WindowEffectConfig, WindowState, WmEvent,
+  CornerStyle, CursorJumpTrigger, DisplayState, EasingConfig, HideMethod,
+  OpacityValue, Rect, UniqueExt, WindowEffectConfig, WindowState, WmEvent,
 };
-use wm_platform::{Platform, ZOrder};
+use wm_platform::{NativeWindow, Platform, ZOrder};
 
 use crate::{
-  models::{Container, WindowContainer},
+  commands::general::{border_overlay, reload_config, run_commands, workspace_osd},
+  models::{scrolling_layout::ScrollingLayout, Container, WindowContainer},
   traits::{CommonGetters, PositionGetters, WindowGetters},
   user_config::UserConfig,
-  wm_state::WmState,
+  wm_state::{ease, lerp_rect, step_spring, WindowAnimation, WmState},
 };
 
 pub fn platform_sync(
   state: &mut WmState,
-  config: &UserConfig,
+  config: &mut UserConfig,
 ) -> anyhow::Result<()> {
+  apply_pending_config_reload(state, config)?;
+  reap_finished_animations(state);
+
   let focused_container =
     state.focused_container().context("No focused container.")?;
 
@@ -38,13 +42,17 @@ pub fn platform_sync(
     jump_cursor(focused_container.clone(), state, config)?;
   }
 
+  if config.value.general.workspace_osd.enabled {
+    sync_workspace_osd(state, config)?;
+  }
+
   if state.pending_sync.needs_focused_effect_update()
     || state.pending_sync.needs_all_effects_update()
   {
     let prev_effects_window = state.prev_effects_window.clone();
 
     if let Ok(window) = focused_container.as_window_container() {
-      apply_window_effects(&window, true, config);
+      apply_window_effects(&window, true, config, state);
       state.prev_effects_window = Some(window.clone());
     } else {
       state.prev_effects_window = None;
@@ -57,10 +65,11 @@ pub fn platform_sync(
         prev_effects_window.into_iter().collect()
       }
       .into_iter()
-      .filter(|window| window.id() != focused_container.id());
+      .filter(|window| window.id() != focused_container.id())
+      .collect::<Vec<_>>();
 
     for window in unfocused_windows {
-      apply_window_effects(&window, false, config);
+      apply_window_effects(&window, false, config, state);
     }
   }
 
@@ -69,6 +78,79 @@ pub fn platform_sync(
   Ok(())
 }
 
+/// Drains `state.config_reload_rx` (coalescing a burst of change
+/// notifications from a single editor save into one reload) and applies
+/// a live config reload if any arrived since the last pass. Runs at the
+/// top of every `platform_sync` call - the natural per-tick home for
+/// this, since `config.reload()` needs `&mut UserConfig` for exactly as
+/// long as the rest of the tick already holds it.
+fn apply_pending_config_reload(
+  state: &mut WmState,
+  config: &mut UserConfig,
+) -> anyhow::Result<()> {
+  let Some(reload_rx) = &mut state.config_reload_rx else {
+    return Ok(());
+  };
+
+  let mut changed = false;
+  while reload_rx.try_recv().is_ok() {
+    changed = true;
+  }
+
+  if !changed {
+    return Ok(());
+  }
+
+  match config.reload() {
+    Ok(()) => {
+      info!("Config file changed. Applying live reload.");
+
+      if let Err(err) = reload_config(state, config) {
+        warn!("Failed to apply reloaded config: {}", err);
+        return Ok(());
+      }
+
+      if let Err(err) = run_commands(
+        &config.value.general.config_reload_commands,
+        state,
+        config,
+      ) {
+        warn!("Failed to run config reload commands: {}", err);
+      }
+    }
+    Err(err) => {
+      state.emit_event(WmEvent::ConfigReloadError {
+        error: err.to_string(),
+      });
+
+      warn!("Failed to reload config, keeping previous config: {}", err);
+    }
+  }
+
+  Ok(())
+}
+
+/// Drops `state.animations`/`state.animation_handles` entries whose task
+/// already ran to completion. The task itself can't reach back into
+/// `WmState` to remove its own entry once it finishes (it's a detached
+/// `'static` task, not holding `&mut WmState`), so a normally-completed
+/// animation would otherwise sit in both maps forever - only the <2px
+/// early-out and a same-window retarget ever cleared them. Checking
+/// `JoinHandle::is_finished()` here, once per tick, reaps them instead.
+fn reap_finished_animations(state: &mut WmState) {
+  let finished = state
+    .animation_handles
+    .iter()
+    .filter(|(_, handle)| handle.is_finished())
+    .map(|(handle, _)| *handle)
+    .collect::<Vec<_>>();
+
+  for handle in finished {
+    state.animation_handles.remove(&handle);
+    state.animations.remove(&handle);
+  }
+}
+
 fn sync_focus(
   focused_container: &Container,
   state: &mut WmState,
@@ -228,8 +310,8 @@ fn redraw_containers(
       },
     );
 
-    let rect = window
-      .to_rect()?
+    let rect = scrolling_column_rect(window, &workspace, state)?
+      .unwrap_or(window.to_rect()?)
       .apply_delta(&window.total_border_delta()?, None);
 
     let is_visible = matches!(
@@ -241,83 +323,239 @@ fn redraw_containers(
     let has_pending_dpi = window.has_pending_dpi_adjustment();
     let window_state = window.state().clone();
     let native_window = window.native().clone();
-    
-    if config.value.general.animations.enabled && is_visible {
-        if let Some(handle) = state.animation_handles.remove(&native_window.handle) {
-            handle.abort();
+
+    if let Some(overlay) = state.border_overlays.get(&native_window.handle)
+    {
+      if let Err(err) = border_overlay::reposition_border_overlay(
+        overlay,
+        rect.x(),
+        rect.y(),
+        rect.width(),
+        rect.height(),
+        Some(native_window.handle),
+        is_visible,
+      ) {
+        warn!("Failed to reposition border overlay: {}", err);
+      }
+    }
+
+    // A display-state transition fades opacity over the same frame
+    // loop as the position animation below, so it always takes the
+    // animated path regardless of the position-delta early-out -
+    // otherwise a window that fades in place (no move/resize) would
+    // pop instead of fading.
+    let is_fading_in = window.display_state() == DisplayState::Showing;
+    let is_fading_out = window.display_state() == DisplayState::Hiding;
+    let is_transitioning = is_fading_in || is_fading_out;
+
+    if config.value.general.animations.enabled && (is_visible || is_fading_out)
+    {
+      let end_rect = rect;
+
+      // Rebase `from` to wherever the window's in-flight animation
+      // currently has it, so retargeting doesn't snap back before
+      // animating onward. A spring's true position isn't derivable
+      // from elapsed time (it's integrated tick by tick by the task
+      // itself), so rebase from the real on-screen frame instead.
+      // Falls back to the real on-screen frame for a window that isn't
+      // already animating.
+      let in_flight_spring = matches!(
+        state.animations.get(&native_window.handle).map(|a| &a.easing),
+        Some(EasingConfig::Spring { .. })
+      );
+
+      let start_rect = if in_flight_spring {
+        native_window.frame_position().ok()
+      } else {
+        state
+          .animations
+          .get(&native_window.handle)
+          .map(WindowAnimation::current_rect)
+          .or_else(|| native_window.frame_position().ok())
+      }
+      .unwrap_or_else(|| end_rect.clone());
+
+      if let Some(handle) =
+        state.animation_handles.remove(&native_window.handle)
+      {
+        handle.abort();
+      }
+
+      if !is_transitioning
+        && (start_rect.x() - end_rect.x()).abs() < 2
+        && (start_rect.y() - end_rect.y()).abs() < 2
+        && (start_rect.width() - end_rect.width()).abs() < 2
+        && (start_rect.height() - end_rect.height()).abs() < 2
+      {
+        state.animations.remove(&native_window.handle);
+
+        if let Err(err) = native_window.set_position(
+          &window_state,
+          &end_rect,
+          &z_order,
+          is_visible,
+          &hide_method,
+          has_pending_dpi,
+        ) {
+          warn!("Failed to set window position: {}", err);
+        }
+
+        continue;
+      }
+
+      // Rebase the fade's starting alpha the same way `start_rect` rebases
+      // position: from wherever the aborted animation's opacity actually
+      // was, not always fully transparent/opaque. Otherwise re-showing a
+      // window mid-fade-out (or vice versa) pops its opacity back to the
+      // fade's nominal start before ramping onward.
+      let start_alpha = state
+        .animations
+        .get(&native_window.handle)
+        .and_then(WindowAnimation::current_alpha)
+        .unwrap_or(if is_fading_in { 0 } else { 255 });
+
+      let animation_config = config.value.general.animations.clone();
+      let duration = Duration::from_millis(animation_config.duration_ms);
+      let easing = animation_config.easing;
+      let start = Instant::now();
+
+      state.animations.insert(
+        native_window.handle,
+        WindowAnimation {
+          from: start_rect.clone(),
+          to: end_rect.clone(),
+          start,
+          duration,
+          easing,
+          fade: is_transitioning.then_some(is_fading_in),
+        },
+      );
+
+      let task = task::spawn(async move {
+        let interval_ms = 1000 / animation_config.fps.max(1);
+        #[allow(clippy::cast_precision_loss)]
+        let dt = interval_ms as f32 / 1000.0;
+        let mut interval =
+          tokio::time::interval(Duration::from_millis(interval_ms));
+
+        // Ticking visibility stays `true` throughout a fade-out so the
+        // window doesn't get hidden/cloaked before its opacity reaches
+        // zero; the real `is_visible` is only applied on the final
+        // tick, once the fade has actually finished.
+        let tick_is_visible = if is_fading_out { true } else { is_visible };
+
+        if let EasingConfig::Spring { stiffness, damping } = easing {
+          #[allow(clippy::cast_precision_loss)]
+          let mut pos = (
+            start_rect.left as f32,
+            start_rect.top as f32,
+            start_rect.right as f32,
+            start_rect.bottom as f32,
+          );
+          let mut vel = (0.0, 0.0, 0.0, 0.0);
+          #[allow(clippy::cast_precision_loss)]
+          let target = (
+            end_rect.left as f32,
+            end_rect.top as f32,
+            end_rect.right as f32,
+            end_rect.bottom as f32,
+          );
+
+          loop {
+            interval.tick().await;
+
+            let settled =
+              step_spring(&mut pos, &mut vel, target, stiffness, damping, dt);
+
+            #[allow(clippy::cast_possible_truncation)]
+            let cur_rect = Rect::from_ltrb(
+              pos.0 as i32,
+              pos.1 as i32,
+              pos.2 as i32,
+              pos.3 as i32,
+            );
+
+            // Spring position has no fixed `t`, but the fade still
+            // rides `duration_ms` as its own ramp length independent
+            // of how long the spring takes to settle.
+            let fade_t = (start.elapsed().as_secs_f32()
+              / duration.as_secs_f32().max(f32::EPSILON))
+            .min(1.0);
+
+            apply_fade_tick(
+              &native_window,
+              is_fading_in,
+              is_fading_out,
+              start_alpha,
+              fade_t,
+            );
+
+            let _ = native_window.set_position(
+              &window_state,
+              if settled { &end_rect } else { &cur_rect },
+              &z_order,
+              tick_is_visible,
+              &hide_method,
+              has_pending_dpi,
+            );
+
+            if settled {
+              break;
+            }
+          }
+        } else {
+          loop {
+            interval.tick().await;
+
+            let elapsed = start.elapsed().as_secs_f32();
+            let t =
+              (elapsed / duration.as_secs_f32().max(f32::EPSILON)).min(1.0);
+
+            let cur_rect =
+              lerp_rect(&start_rect, &end_rect, ease(&easing, t));
+
+            apply_fade_tick(
+              &native_window,
+              is_fading_in,
+              is_fading_out,
+              start_alpha,
+              t,
+            );
+
+            let _ = native_window.set_position(
+              &window_state,
+              &cur_rect,
+              &z_order,
+              tick_is_visible,
+              &hide_method,
+              has_pending_dpi,
+            );
+
+            if t >= 1.0 {
+              break;
+            }
+          }
         }
 
-        let animation_config = config.value.general.animations.clone();
-        
-        let task = task::spawn(async move {
-             let start_rect = match native_window.frame_position() {
-                 Ok(r) => r,
-                 Err(_) => rect.clone()
-             };
-             
-             let end_rect = rect;
-             
-             if (start_rect.x() - end_rect.x()).abs() < 2 && 
-                (start_rect.y() - end_rect.y()).abs() < 2 &&
-                (start_rect.width() - end_rect.width()).abs() < 2 &&
-                (start_rect.height() - end_rect.height()).abs() < 2 {
-                 
-                 let _ = native_window.set_position(
-                    &window_state,
-                    &end_rect,
-                    &z_order,
-                    is_visible,
-                    &hide_method,
-                    has_pending_dpi,
-                 );
-                 return;
-             }
-
-             let _duration = Duration::from_millis(animation_config.duration_ms);
-             let fps = animation_config.fps;
-             let interval_ms = 1000 / fps;
-             #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-             let steps = (animation_config.duration_ms as f64 / interval_ms as f64) as u32;
-             
-             let mut interval = tokio::time::interval(Duration::from_millis(interval_ms));
-             
-             for i in 1..=steps {
-                 interval.tick().await;
-                 #[allow(clippy::cast_precision_loss)]
-                 let t = i as f32 / steps as f32;
-                 // Easing: Cubic Out (1 - (1-t)^3)
-                 let t = 1.0 - (1.0 - t).powi(3); 
-                 
-                 #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
-                 let cur_rect = wm_common::Rect::from_ltrb(
-                     (start_rect.left as f32 + (end_rect.left as f32 - start_rect.left as f32) * t) as i32,
-                     (start_rect.top as f32 + (end_rect.top as f32 - start_rect.top as f32) * t) as i32,
-                     (start_rect.right as f32 + (end_rect.right as f32 - start_rect.right as f32) * t) as i32,
-                     (start_rect.bottom as f32 + (end_rect.bottom as f32 - start_rect.bottom as f32) * t) as i32,
-                 );
-
-                 let _ = native_window.set_position(
-                    &window_state,
-                    &cur_rect,
-                    &z_order,
-                    is_visible,
-                    &hide_method,
-                    has_pending_dpi,
-                 );
-             }
-             
-             let _ = native_window.set_position(
-                &window_state,
-                &end_rect,
-                &z_order,
-                is_visible,
-                &hide_method,
-                has_pending_dpi,
-             );
-        });
-        
-        state.animation_handles.insert(window.native().handle, task);
-        
+        apply_fade_tick(
+          &native_window,
+          is_fading_in,
+          is_fading_out,
+          start_alpha,
+          1.0,
+        );
+
+        let _ = native_window.set_position(
+          &window_state,
+          &end_rect,
+          &z_order,
+          is_visible,
+          &hide_method,
+          has_pending_dpi,
+        );
+      });
+
+      state.animation_handles.insert(native_window.handle, task);
     } else if let Err(err) = native_window.set_position(
           &window.state(),
           &rect,
@@ -362,6 +600,26 @@ fn redraw_containers(
   Ok(())
 }
 
+/// Computed rect for `window` under its workspace's scrolling-tiling
+/// layout, if it has one and the window is a column member. Windows
+/// whose column currently scrolls entirely off the monitor are still
+/// given a real (off-screen) rect rather than being hidden, so they can
+/// animate back into view smoothly once scrolled back in.
+fn scrolling_column_rect(
+  window: &WindowContainer,
+  workspace: &crate::models::Workspace,
+  state: &WmState,
+) -> anyhow::Result<Option<wm_common::Rect>> {
+  let Some(layout) = state.scrolling_layouts.get(&workspace.id()) else {
+    return Ok(None);
+  };
+
+  let workspace_rect = workspace.to_rect()?;
+  let column_width = ScrollingLayout::column_width(&workspace_rect);
+
+  Ok(layout.window_rect(window.id(), &workspace_rect, column_width))
+}
+
 fn jump_cursor(
   focused_container: Container,
   state: &WmState,
@@ -396,10 +654,118 @@ fn jump_cursor(
   Ok(())
 }
 
+/// Diffs each monitor's `displayed_workspace()` against
+/// `WmState.last_displayed_workspaces` and shows `workspace_osd` on
+/// whichever monitor's displayed workspace just changed. Diffing
+/// per-monitor (rather than keying off `WmEvent::FocusChanged`) means a
+/// focus move within the already-displayed workspace doesn't trigger
+/// the OSD - only an actual workspace switch does.
+fn sync_workspace_osd(
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let mut switched = None;
+
+  for monitor in state.monitors() {
+    let Some(workspace) = monitor.displayed_workspace() else {
+      continue;
+    };
+
+    let prev_workspace_id = state
+      .last_displayed_workspaces
+      .insert(monitor.id(), workspace.id());
+
+    if prev_workspace_id.is_some_and(|id| id != workspace.id()) {
+      switched = Some((monitor, workspace));
+    }
+  }
+
+  let Some((monitor, workspace)) = switched else {
+    return Ok(());
+  };
+
+  show_workspace_osd(&monitor, &workspace, state, config)
+}
+
+/// Creates `state.workspace_osd` on first use, positions it over
+/// `monitor` per `WorkspaceOsdConfig.position`, paints `workspace`'s
+/// name, and (re)starts the task that fades it out after
+/// `WorkspaceOsdConfig.timeout_ms`.
+fn show_workspace_osd(
+  monitor: &crate::models::Monitor,
+  workspace: &crate::models::Workspace,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let osd_config = config.value.general.workspace_osd.clone();
+
+  if state.workspace_osd.is_none() {
+    state.workspace_osd = Some(workspace_osd::create_workspace_osd()?);
+  }
+
+  let Some(osd) = &state.workspace_osd else {
+    return Ok(());
+  };
+
+  workspace_osd::set_workspace_osd_content(
+    workspace.config().name.clone(),
+    osd_config.text_color.clone(),
+    osd_config.background_color.clone(),
+    osd_config.font_family.clone(),
+    osd_config.font_size,
+  );
+
+  let width = 220;
+  let height = osd_config.font_size * 2 + 24;
+  let monitor_rect = monitor.to_rect()?;
+  let (x, y) = workspace_osd::resolve_osd_position(
+    &monitor_rect,
+    osd_config.position,
+    osd_config.edge_offset,
+    width,
+    height,
+  );
+
+  workspace_osd::position_workspace_osd(osd, x, y, width, height, true)?;
+  Platform::set_osd_overlay_opacity(osd.handle, 255)?;
+
+  if let Some(handle) = state.workspace_osd_fade_handle.take() {
+    handle.abort();
+  }
+
+  let overlay_handle = osd.handle;
+  let timeout = Duration::from_millis(osd_config.timeout_ms);
+
+  state.workspace_osd_fade_handle = Some(task::spawn(async move {
+    tokio::time::sleep(timeout).await;
+    fade_out_workspace_osd(overlay_handle).await;
+  }));
+
+  Ok(())
+}
+
+/// Ramps the OSD's layered-window alpha from full to zero over a fixed
+/// 200ms, then hides it. Run as a detached task so
+/// `show_workspace_osd` isn't blocked waiting for the fade to finish.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+async fn fade_out_workspace_osd(overlay_handle: isize) {
+  const FADE_STEPS: i32 = 20;
+  let step_duration = Duration::from_millis(200) / FADE_STEPS as u32;
+
+  for step in (0..=FADE_STEPS).rev() {
+    let alpha = (step as f32 / FADE_STEPS as f32 * 255.0) as u8;
+    _ = Platform::set_osd_overlay_opacity(overlay_handle, alpha);
+    tokio::time::sleep(step_duration).await;
+  }
+
+  _ = Platform::position_osd_overlay(overlay_handle, 0, 0, 0, 0, false);
+}
+
 fn apply_window_effects(
   window: &WindowContainer,
   is_focused: bool,
   config: &UserConfig,
+  state: &mut WmState,
 ) {
   let window_effects = &config.value.window_effects;
 
@@ -412,7 +778,7 @@ fn apply_window_effects(
   if window_effects.focused_window.border.enabled
     || window_effects.other_windows.border.enabled
   {
-    apply_border_effect(window, effect_config);
+    apply_border_effect(window, effect_config, state);
   }
 
   if window_effects.focused_window.hide_title_bar.enabled
@@ -437,22 +803,105 @@ fn apply_window_effects(
 fn apply_border_effect(
   window: &WindowContainer,
   effect_config: &WindowEffectConfig,
+  state: &mut WmState,
 ) {
-  let border_color = if effect_config.border.enabled {
-    Some(&effect_config.border.color)
-  } else {
-    None
+  let handle = window.native().handle;
+
+  // Drawing a custom overlay and setting DWM's accent border are
+  // mutually exclusive; tear down whichever one isn't the active mode so
+  // a live config reload can flip between them cleanly.
+  if !effect_config.border.native_drawn || !effect_config.border.enabled {
+    state.border_overlays.remove(&handle);
+  }
+
+  if !effect_config.border.native_drawn {
+    let border_color = if effect_config.border.enabled {
+      Some(&effect_config.border.color)
+    } else {
+      None
+    };
+
+    _ = window.native().set_border_color(border_color);
+
+    let native = window.native().clone();
+    let border_color = border_color.cloned();
+
+    task::spawn(async move {
+      tokio::time::sleep(Duration::from_millis(50)).await;
+      _ = native.set_border_color(border_color.as_ref());
+    });
+
+    return;
+  }
+
+  // Custom-drawn mode: clear any DWM accent border left over from DWM
+  // mode.
+  _ = window.native().set_border_color(None);
+
+  if !effect_config.border.enabled {
+    return;
+  }
+
+  sync_border_overlay(window, effect_config, state);
+}
+
+/// Drives a custom-drawn border overlay for `window`, creating it lazily
+/// on first use. Unlike DWM's accent border, this supports a
+/// configurable `thickness`/`border_offset` and renders identically on
+/// Windows 10 and 11.
+fn sync_border_overlay(
+  window: &WindowContainer,
+  effect_config: &WindowEffectConfig,
+  state: &mut WmState,
+) {
+  let handle = window.native().handle;
+  let color = effect_config.border.color.clone();
+  let thickness = effect_config.border.thickness;
+
+  if !state.border_overlays.contains_key(&handle) {
+    match border_overlay::create_border_overlay(
+      color.clone(),
+      thickness,
+      effect_config.border.border_offset,
+    ) {
+      Ok(overlay) => {
+        state.border_overlays.insert(handle, overlay);
+      }
+      Err(err) => {
+        warn!("Failed to create border overlay: {}", err);
+        return;
+      }
+    }
+  }
+
+  let Some(overlay) = state.border_overlays.get(&handle) else {
+    return;
   };
 
-  _ = window.native().set_border_color(border_color);
+  border_overlay::set_border_overlay_style(overlay, color, thickness);
 
-  let native = window.native().clone();
-  let border_color = border_color.cloned();
+  let Ok(rect) = window
+    .to_rect()
+    .and_then(|rect| window.total_border_delta().map(|delta| (rect, delta)))
+    .map(|(rect, delta)| rect.apply_delta(&delta, None))
+  else {
+    return;
+  };
 
-  task::spawn(async move {
-    tokio::time::sleep(Duration::from_millis(50)).await;
-    _ = native.set_border_color(border_color.as_ref());
-  });
+  if let Err(err) = border_overlay::reposition_border_overlay(
+    overlay,
+    rect.x(),
+    rect.y(),
+    rect.width(),
+    rect.height(),
+    Some(handle),
+    matches!(
+      window.display_state(),
+      DisplayState::Showing | DisplayState::Shown
+    ),
+  ) {
+    warn!("Failed to reposition border overlay: {}", err);
+  }
 }
 
 fn apply_hide_title_bar_effect(
@@ -488,4 +937,32 @@ fn apply_transparency_effect(
   };
 
   _ = window.native().set_transparency(transparency);
+}
+
+/// Ramps a window's opacity across a display-state transition: from
+/// `start_alpha` (wherever a retargeted fade actually left off, or fully
+/// transparent/opaque for a fresh one) to full as it enters `Showing`,
+/// the reverse as it enters `Hiding`, so it fades in/out over
+/// `redraw_containers`' animation frame loop instead of popping. A no-op
+/// when neither flag is set.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn apply_fade_tick(
+  native_window: &NativeWindow,
+  is_fading_in: bool,
+  is_fading_out: bool,
+  start_alpha: u8,
+  t: f32,
+) {
+  let target_alpha: f32 = if is_fading_in {
+    255.0
+  } else if is_fading_out {
+    0.0
+  } else {
+    return;
+  };
+
+  let t = t.clamp(0.0, 1.0);
+  let alpha = f32::from(start_alpha) + (target_alpha - f32::from(start_alpha)) * t;
+
+  _ = native_window.set_transparency(&OpacityValue::from_alpha(alpha as u8));
 }
\ No newline at end of file
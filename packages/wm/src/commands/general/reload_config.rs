@@ -0,0 +1,43 @@
+use tracing::info;
+
+use crate::{
+  commands::window::run_window_rules, user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Re-applies the parts of a freshly re-parsed config that can change
+/// live, without restarting the WM. Assumes `config` has already been
+/// swapped to the new, successfully-parsed `ParsedConfig` (see
+/// `UserConfig::reload`).
+pub fn reload_config(
+  state: &mut WmState,
+  config: &mut UserConfig,
+) -> anyhow::Result<()> {
+  state.binding_modes = config.value.binding_modes.clone();
+
+  // Re-run `TitleChange` rules for already-managed windows so that
+  // edits to `window_rules` (e.g. a new float/workspace rule) take
+  // effect immediately instead of only for new windows. Rules whose
+  // `on` list omits `title_change` (e.g. `on: [manage]`) are not re-run.
+  for window in state.windows() {
+    run_window_rules(
+      window.clone(),
+      &wm_common::WindowRuleEvent::TitleChange,
+      state,
+      config,
+    )?;
+  }
+
+  // Gaps, window effects, and keybindings are read directly off
+  // `config.value` wherever they're needed, so queuing a full redraw
+  // and effects update is enough to make them take visible effect.
+  for workspace in state.workspaces() {
+    state.pending_sync.queue_container_to_redraw(workspace.into());
+  }
+
+  state.pending_sync.queue_all_effects_update();
+
+  info!("Applied live config reload.");
+
+  Ok(())
+}
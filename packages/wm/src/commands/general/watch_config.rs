@@ -0,0 +1,32 @@
+use std::path::Path;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+/// Starts a background watcher on `config_path` that forwards a `()` on
+/// `reload_tx` for every modify/create event. Applying the reload itself
+/// happens synchronously on the main thread - see
+/// `platform_sync::apply_pending_config_reload`, which drains and
+/// coalesces these notifications once per tick, since swapping in the
+/// reparsed config needs `&mut UserConfig`/`&mut WmState` for exactly as
+/// long as the rest of that tick already holds them. The returned
+/// `RecommendedWatcher` must be kept alive for the lifetime of the WM
+/// (see `WmState::populate`/`WmState._config_watcher`); dropping it
+/// stops the watch.
+pub fn start_config_watcher(
+  config_path: &Path,
+  reload_tx: mpsc::UnboundedSender<()>,
+) -> anyhow::Result<RecommendedWatcher> {
+  let mut watcher =
+    notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+      if let Ok(event) = res {
+        if event.kind.is_modify() || event.kind.is_create() {
+          let _ = reload_tx.send(());
+        }
+      }
+    })?;
+
+  watcher.watch(config_path, RecursiveMode::NonRecursive)?;
+
+  Ok(watcher)
+}
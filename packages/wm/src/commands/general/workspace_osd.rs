@@ -0,0 +1,203 @@
+use std::sync::{Mutex, OnceLock};
+
+use wm_common::{Color, OsdPosition, Rect};
+use windows::{
+  core::PCWSTR,
+  Win32::{
+    Foundation::{COLORREF, HWND, LPARAM, LRESULT, RECT, WPARAM},
+    Graphics::Gdi::{
+      BeginPaint, CreateFontW, CreateSolidBrush, DeleteObject, DrawTextW,
+      EndPaint, FillRect, GetClientRect, SelectObject, SetBkMode,
+      SetTextColor, DT_CENTER, DT_SINGLELINE, DT_VCENTER, FW_SEMIBOLD,
+      PAINTSTRUCT, TRANSPARENT,
+    },
+    UI::WindowsAndMessaging::{DefWindowProcW, WM_PAINT},
+  },
+};
+use wm_platform::Platform;
+
+/// A topmost, click-through layered overlay showing the workspace
+/// switched to, auto-faded out after `WorkspaceOsdConfig.timeout_ms`.
+/// Unlike `BorderOverlay` (one per tracked window), a single instance is
+/// created lazily and reused - repositioned and re-texted - for every
+/// switch; see `sync_workspace_osd` in `platform_sync`.
+pub struct WorkspaceOsd {
+  pub handle: isize,
+}
+
+impl Drop for WorkspaceOsd {
+  fn drop(&mut self) {
+    OSD_STYLE.get_or_init(Default::default).lock().unwrap().take();
+    Platform::destroy_osd_overlay(self.handle);
+  }
+}
+
+/// Text + colors/font currently painted by the OSD overlay. The bare
+/// `WNDPROC` function pointer below has no way to capture state, so its
+/// `WM_PAINT` handler looks style up here instead (mirroring
+/// `border_overlay::OVERLAY_STYLES`).
+static OSD_STYLE: OnceLock<Mutex<Option<OsdStyle>>> = OnceLock::new();
+
+#[derive(Clone)]
+struct OsdStyle {
+  text: String,
+  text_color: Color,
+  background_color: Color,
+  font_family: String,
+  font_size: i32,
+}
+
+/// Creates the workspace OSD overlay window. Call
+/// `set_workspace_osd_content` and `position_workspace_osd` afterwards;
+/// a freshly created overlay starts out hidden.
+pub fn create_workspace_osd() -> anyhow::Result<WorkspaceOsd> {
+  let handle =
+    Platform::create_osd_overlay_window(Some(workspace_osd_wndproc))?;
+
+  Ok(WorkspaceOsd { handle })
+}
+
+/// Updates the text/style the OSD paints on its next `WM_PAINT`.
+pub fn set_workspace_osd_content(
+  text: String,
+  text_color: Color,
+  background_color: Color,
+  font_family: String,
+  font_size: i32,
+) {
+  OSD_STYLE.get_or_init(Default::default).lock().unwrap().replace(
+    OsdStyle {
+      text,
+      text_color,
+      background_color,
+      font_family,
+      font_size,
+    },
+  );
+}
+
+/// Moves `overlay` to `x`/`y`/`width`/`height` (already resolved via
+/// `resolve_osd_position`) and shows or hides it.
+pub fn position_workspace_osd(
+  overlay: &WorkspaceOsd,
+  x: i32,
+  y: i32,
+  width: i32,
+  height: i32,
+  is_visible: bool,
+) -> anyhow::Result<()> {
+  Platform::position_osd_overlay(overlay.handle, x, y, width, height, is_visible)
+}
+
+/// Resolves `WorkspaceOsdConfig.position`/`edge_offset` against
+/// `monitor_rect` for a panel of `width`x`height`, returning its
+/// top-left corner.
+#[must_use]
+pub fn resolve_osd_position(
+  monitor_rect: &Rect,
+  position: OsdPosition,
+  edge_offset: i32,
+  width: i32,
+  height: i32,
+) -> (i32, i32) {
+  match position {
+    OsdPosition::TopLeft => {
+      (monitor_rect.left + edge_offset, monitor_rect.top + edge_offset)
+    }
+    OsdPosition::TopRight => (
+      monitor_rect.right - edge_offset - width,
+      monitor_rect.top + edge_offset,
+    ),
+    OsdPosition::BottomLeft => (
+      monitor_rect.left + edge_offset,
+      monitor_rect.bottom - edge_offset - height,
+    ),
+    OsdPosition::BottomRight => (
+      monitor_rect.right - edge_offset - width,
+      monitor_rect.bottom - edge_offset - height,
+    ),
+    OsdPosition::Center => (
+      monitor_rect.left + (monitor_rect.width() - width) / 2,
+      monitor_rect.top + (monitor_rect.height() - height) / 2,
+    ),
+  }
+}
+
+unsafe extern "system" fn workspace_osd_wndproc(
+  handle: HWND,
+  message: u32,
+  wparam: WPARAM,
+  lparam: LPARAM,
+) -> LRESULT {
+  if message == WM_PAINT {
+    paint_workspace_osd(handle);
+    return LRESULT(0);
+  }
+
+  DefWindowProcW(handle, message, wparam, lparam)
+}
+
+fn paint_workspace_osd(handle: HWND) {
+  let style =
+    OSD_STYLE.get_or_init(Default::default).lock().unwrap().clone();
+
+  let Some(style) = style else {
+    return;
+  };
+
+  unsafe {
+    let mut paint_struct = PAINTSTRUCT::default();
+    let hdc = BeginPaint(handle, &mut paint_struct);
+
+    let mut client_rect = RECT::default();
+    _ = GetClientRect(handle, &mut client_rect);
+
+    let background = CreateSolidBrush(color_ref(&style.background_color));
+    FillRect(hdc, &client_rect, background);
+    _ = DeleteObject(background);
+
+    let font_name = to_wide(&style.font_family);
+    let font = CreateFontW(
+      -style.font_size,
+      0,
+      0,
+      0,
+      FW_SEMIBOLD.0 as i32,
+      0,
+      0,
+      0,
+      0,
+      0,
+      0,
+      0,
+      0,
+      PCWSTR(font_name.as_ptr()),
+    );
+    let prev_font = SelectObject(hdc, font);
+
+    SetBkMode(hdc, TRANSPARENT);
+    SetTextColor(hdc, color_ref(&style.text_color));
+
+    let mut text = to_wide(&style.text);
+    DrawTextW(
+      hdc,
+      &mut text,
+      &mut client_rect,
+      DT_CENTER | DT_VCENTER | DT_SINGLELINE,
+    );
+
+    SelectObject(hdc, prev_font);
+    _ = DeleteObject(font);
+    _ = EndPaint(handle, &paint_struct);
+  }
+}
+
+fn color_ref(color: &Color) -> COLORREF {
+  COLORREF(
+    u32::from(color.b) << 16 | u32::from(color.g) << 8 | u32::from(color.r),
+  )
+}
+
+fn to_wide(text: &str) -> Vec<u16> {
+  text.encode_utf16().chain(std::iter::once(0)).collect()
+}
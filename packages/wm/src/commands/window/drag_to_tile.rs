@@ -0,0 +1,161 @@
+use anyhow::Context;
+use wm_common::{Point, Rect, TilingDirection, WindowState};
+use wm_platform::Platform;
+
+use crate::{
+  commands::{
+    container::{
+      attach_container, detach_container, set_tiling_direction,
+      wrap_in_split_container,
+    },
+    window::update_window_state,
+  },
+  models::{Container, SplitContainer, WindowContainer},
+  traits::{CommonGetters, PositionGetters, TilingDirectionGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Where a dropped drag lands relative to the hit-tested target window,
+/// mirroring the dominant-axis quadrant test `insertion_target` uses for
+/// `LayoutConfig::FocusedMouse` so mouse-driven re-tiling picks the same
+/// side/direction a keybinding-driven insert would.
+enum DropZone {
+  /// Cursor released over the target's middle third: swap the dragged
+  /// and target containers' tree positions.
+  Swap,
+  /// Cursor released over one of the target's outer thirds: insert the
+  /// dragged window as a new split on that side.
+  Split(TilingDirection, bool),
+}
+
+const DROP_ZONE_SWAP_FRACTION: f32 = 1.0 / 3.0;
+
+fn compute_drop_zone(rect: &Rect, point: &Point) -> DropZone {
+  let center = rect.center_point();
+  #[allow(clippy::cast_precision_loss)]
+  let delta_x = (point.x - center.x) as f32;
+  #[allow(clippy::cast_precision_loss)]
+  let delta_y = (point.y - center.y) as f32;
+  #[allow(clippy::cast_precision_loss)]
+  let half_width = rect.width() as f32 / 2.0;
+  #[allow(clippy::cast_precision_loss)]
+  let half_height = rect.height() as f32 / 2.0;
+
+  let frac_x = delta_x.abs() / half_width;
+  let frac_y = delta_y.abs() / half_height;
+
+  if frac_x < DROP_ZONE_SWAP_FRACTION && frac_y < DROP_ZONE_SWAP_FRACTION {
+    return DropZone::Swap;
+  }
+
+  if frac_x > frac_y {
+    DropZone::Split(TilingDirection::Horizontal, delta_x > 0.0)
+  } else {
+    DropZone::Split(TilingDirection::Vertical, delta_y > 0.0)
+  }
+}
+
+/// Called on drag release (`events::handle_mouse_move`) for a window
+/// that was floated for an Alt+drag move. Hit-tests `drop_point` against
+/// the window tree and, if it lands on another tiled window, either
+/// swaps the two containers' tree positions or inserts `dragged` as a
+/// new split alongside the target - whichever `compute_drop_zone`
+/// picks. Dropping over empty space, or back onto itself, leaves
+/// `dragged` floating right where the drag left it.
+pub fn drop_dragged_window(
+  dragged: WindowContainer,
+  drop_point: Point,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let target = Platform::window_from_point(&drop_point)
+    .and_then(|window| Platform::root_ancestor(&window))
+    .map(|root| state.window_from_native(&root))?;
+
+  let Some(target) = target else {
+    return Ok(());
+  };
+
+  if target.id() == dragged.id() {
+    return Ok(());
+  }
+
+  let Ok(target_tiling) = target.as_container().as_tiling_container() else {
+    return Ok(());
+  };
+
+  let target_rect = target_tiling.to_rect()?;
+  let drop_zone = compute_drop_zone(&target_rect, &drop_point);
+
+  let dragged_container: Container = dragged.clone().into();
+
+  match drop_zone {
+    DropZone::Swap => {
+      swap_containers(&dragged_container, &target_tiling.clone().into())?;
+    }
+    DropZone::Split(desired_dir, insert_after) => {
+      detach_container(&dragged_container)?;
+
+      let parent = target_tiling.parent().context("No parent.")?;
+      let current_dir = parent.tiling_direction();
+
+      if current_dir == desired_dir {
+        let index = target_tiling.index() + usize::from(insert_after);
+        attach_container(&dragged_container, &parent, Some(index))?;
+      } else if parent.child_count() == 1 {
+        set_tiling_direction(&parent, state, config, &desired_dir)?;
+        attach_container(
+          &dragged_container,
+          &parent,
+          Some(usize::from(insert_after)),
+        )?;
+      } else {
+        let split =
+          SplitContainer::new(None, desired_dir, None, Vec::new(), None);
+
+        wrap_in_split_container(&split, &parent, &[target_tiling.clone()])?;
+
+        attach_container(
+          &dragged_container,
+          &split.into(),
+          Some(usize::from(insert_after)),
+        )?;
+      }
+    }
+  }
+
+  update_window_state(dragged.clone(), WindowState::Tiling, state, config)?;
+
+  let workspace = dragged.workspace().context("No workspace.")?;
+  state.pending_sync.queue_workspace_to_reorder(workspace);
+  state.pending_sync.queue_focus_change();
+
+  Ok(())
+}
+
+/// Swaps `a` and `b`'s positions in the tree by detaching both and
+/// reattaching each at the other's old parent/index, accounting for the
+/// index shift a same-parent detach causes its later siblings.
+fn swap_containers(a: &Container, b: &Container) -> anyhow::Result<()> {
+  let a_parent = a.parent().context("No parent.")?;
+  let b_parent = b.parent().context("No parent.")?;
+  let a_index = a.index();
+  let b_index = b.index();
+  let same_parent = a_parent.id() == b_parent.id();
+
+  detach_container(a)?;
+  detach_container(b)?;
+
+  // With both detached from a shared parent, refilling the lower slot
+  // first puts every sibling between them back at its original index.
+  if same_parent && b_index < a_index {
+    attach_container(a, &b_parent, Some(b_index))?;
+    attach_container(b, &a_parent, Some(a_index))?;
+  } else {
+    attach_container(b, &a_parent, Some(a_index))?;
+    attach_container(a, &b_parent, Some(b_index))?;
+  }
+
+  Ok(())
+}
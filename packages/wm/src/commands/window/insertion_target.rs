@@ -0,0 +1,255 @@
+use anyhow::Context;
+use wm_common::{LayoutConfig, TilingDirection, WindowState};
+use wm_platform::Platform;
+
+use crate::{
+  commands::container::{set_tiling_direction, wrap_in_split_container},
+  models::{Container, SplitContainer},
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters,
+    TilingSizeGetters,
+  },
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Direction and side to split the focused window's leaf on, resolved
+/// from the workspace's active `LayoutConfig`.
+struct SplitChoice {
+  direction: TilingDirection,
+  insert_after: bool,
+}
+
+/// Resolves where a newly managed window should be attached in the
+/// tiling tree, delegating the actual split decision to whichever
+/// `LayoutConfig` is active for the focused workspace (per-workspace
+/// `layout`, falling back to `general.default_layout`).
+pub fn insertion_target(
+  window_state: &WindowState,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<(Container, usize)> {
+  let focused_container =
+    state.focused_container().context("No focused container.")?;
+
+  let focused_workspace =
+    focused_container.workspace().context("No workspace.")?;
+
+  if *window_state == WindowState::Tiling {
+    if let Ok(focused_tiling) = focused_container.as_tiling_container() {
+      let layout = focused_workspace
+        .config()
+        .layout
+        .clone()
+        .unwrap_or_else(|| config.value.general.default_layout.clone());
+
+      let split_choice = match layout {
+        LayoutConfig::FocusedMouse => {
+          focused_mouse_split(&focused_tiling)?
+        }
+        LayoutConfig::Dwindle => Some(depth_split(&focused_tiling, false)),
+        LayoutConfig::Spiral => Some(depth_split(&focused_tiling, true)),
+        LayoutConfig::MasterStack { master_ratio } => {
+          return master_stack_target(
+            &focused_workspace,
+            &focused_container,
+            master_ratio,
+          );
+        }
+      };
+
+      if let Some(SplitChoice {
+        direction: desired_dir,
+        insert_after,
+      }) = split_choice
+      {
+        let parent = focused_tiling.parent().context("No parent")?;
+        let current_dir = parent.tiling_direction();
+
+        if current_dir == desired_dir {
+          let index = focused_tiling.index();
+          return Ok((
+            parent,
+            if insert_after { index + 1 } else { index },
+          ));
+        }
+
+        // Different direction. If the parent only has the focused
+        // window as a child, we can just flip its direction in place.
+        if parent.child_count() == 1 {
+          set_tiling_direction(&parent, state, config, &desired_dir)?;
+          return Ok((parent, if insert_after { 1 } else { 0 }));
+        }
+
+        // Otherwise wrap the focused window in a new split container
+        // in the desired direction.
+        let split =
+          SplitContainer::new(None, desired_dir, None, Vec::new(), None);
+
+        wrap_in_split_container(&split, &parent, &[focused_tiling.clone()])?;
+
+        return Ok((split.into(), if insert_after { 1 } else { 0 }));
+      }
+    }
+
+    // Fallback shared by every layout: insert after the most recently
+    // focused tiling window, or append to the workspace if there isn't
+    // one yet.
+    let sibling = match focused_container {
+      Container::TilingWindow(_) => Some(focused_container),
+      _ => focused_workspace
+        .descendant_focus_order()
+        .find(Container::is_tiling_window),
+    };
+
+    if let Some(sibling) = sibling {
+      return Ok((
+        sibling.parent().context("No parent.")?,
+        sibling.index() + 1,
+      ));
+    }
+  }
+
+  Ok((
+    focused_workspace.clone().into(),
+    focused_workspace.child_count(),
+  ))
+}
+
+/// Quadrant-of-the-focused-window split, chosen by where the mouse is
+/// hovering relative to its center. Returns `None` when the cursor
+/// isn't over the focused window (e.g. it was triggered by a keybinding
+/// instead of a click), in which case the caller falls back.
+fn focused_mouse_split(
+  focused_tiling: &impl PositionGetters,
+) -> anyhow::Result<Option<SplitChoice>> {
+  let Ok(mouse_pos) = Platform::mouse_position() else {
+    return Ok(None);
+  };
+
+  let rect = focused_tiling.to_rect()?;
+
+  if !rect.contains_point(&mouse_pos) {
+    return Ok(None);
+  }
+
+  let center = rect.center_point();
+  #[allow(clippy::cast_precision_loss)]
+  let delta_x = (mouse_pos.x - center.x) as f32;
+  #[allow(clippy::cast_precision_loss)]
+  let delta_y = (mouse_pos.y - center.y) as f32;
+  #[allow(clippy::cast_precision_loss)]
+  let width = rect.width() as f32;
+  #[allow(clippy::cast_precision_loss)]
+  let height = rect.height() as f32;
+
+  let direction = if (delta_x.abs() / width) > (delta_y.abs() / height) {
+    TilingDirection::Horizontal
+  } else {
+    TilingDirection::Vertical
+  };
+
+  let insert_after = match direction {
+    TilingDirection::Horizontal => delta_x > 0.0,
+    TilingDirection::Vertical => delta_y > 0.0,
+  };
+
+  Ok(Some(SplitChoice {
+    direction,
+    insert_after,
+  }))
+}
+
+/// BSP split shared by dwindle and spiral: alternate direction by depth
+/// (even depth -> horizontal, odd -> vertical) so windows dwindle into a
+/// corner. Spiral additionally alternates the insert-after side each
+/// level so new windows wind around instead of collecting in one
+/// corner.
+fn depth_split(
+  focused_tiling: &impl CommonGetters,
+  alternate_side: bool,
+) -> SplitChoice {
+  let depth = container_depth(focused_tiling);
+
+  let direction = if depth % 2 == 0 {
+    TilingDirection::Horizontal
+  } else {
+    TilingDirection::Vertical
+  };
+
+  let insert_after = if alternate_side { depth % 2 == 0 } else { true };
+
+  SplitChoice {
+    direction,
+    insert_after,
+  }
+}
+
+fn container_depth(container: &impl CommonGetters) -> usize {
+  let mut depth = 0;
+  let mut current = container.parent();
+
+  while let Some(parent) = current {
+    depth += 1;
+    current = parent.parent();
+  }
+
+  depth
+}
+
+/// Master-stack layout: the first tiling window on the workspace
+/// becomes the master, sized by `master_ratio`; every later window is
+/// inserted into that same split as one of the master's siblings. The
+/// stack windows are not grouped into a split of their own.
+fn master_stack_target(
+  focused_workspace: &crate::models::Workspace,
+  focused_container: &Container,
+  master_ratio: f32,
+) -> anyhow::Result<(Container, usize)> {
+  let existing_tiling = focused_workspace
+    .descendant_focus_order()
+    .find(Container::is_tiling_window);
+
+  let Some(master) = existing_tiling else {
+    // First window on the workspace becomes the master.
+    return Ok((
+      focused_workspace.clone().into(),
+      focused_workspace.child_count(),
+    ));
+  };
+
+  let parent = master.parent().context("No parent.")?;
+
+  // A lone master with no stack yet: wrap it so its split ratio can be
+  // set to `master_ratio` and subsequent windows join the stack side.
+  if parent.child_count() == 1 {
+    let split = SplitContainer::new(
+      None,
+      TilingDirection::Horizontal,
+      None,
+      Vec::new(),
+      None,
+    );
+
+    wrap_in_split_container(&split, &parent, &[master.clone()])?;
+
+    // The master keeps `master_ratio` of the split; the stack window
+    // inserted at index 1 takes up the remainder.
+    master.set_tiling_size(master_ratio);
+
+    return Ok((split.into(), 1));
+  }
+
+  // Insert new windows after whichever tiling window is currently
+  // focused (or after the most recently focused tiling window if focus
+  // is elsewhere).
+  let stack_anchor = match focused_container {
+    Container::TilingWindow(_) => focused_container.clone(),
+    _ => master,
+  };
+
+  Ok((
+    stack_anchor.parent().context("No parent.")?,
+    stack_anchor.index() + 1,
+  ))
+}
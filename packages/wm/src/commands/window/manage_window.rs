@@ -1,19 +1,22 @@
 use anyhow::Context;
 use tracing::info;
 use wm_common::{
-  try_warn, LengthValue, RectDelta, WindowRuleEvent, WindowState, WmEvent, TilingDirection
+  try_warn, GeometryAnchor, InitialGeometryConfig, LengthValue,
+  RectDelta, WindowMatchAttrs, WindowMatchConfig, WindowRuleEvent,
+  WindowState, WmEvent,
 };
-use wm_platform::{NativeWindow, Platform};
+use wm_platform::NativeWindow;
 
 use crate::{
   commands::{
-    container::{attach_container, set_focused_descendant, wrap_in_split_container, set_tiling_direction},
-    window::run_window_rules,
+    container::{attach_container, set_focused_descendant},
+    window::{insertion_target, run_window_rules},
   },
   models::{
-    Container, Monitor, NonTilingWindow, TilingWindow, WindowContainer, SplitContainer
+    scrolling_layout::Column, Container, Monitor, NonTilingWindow,
+    TilingWindow, WindowContainer,
   },
-  traits::{CommonGetters, PositionGetters, WindowGetters, TilingDirectionGetters},
+  traits::{CommonGetters, PositionGetters, WindowGetters},
   user_config::UserConfig,
   wm_state::WmState,
 };
@@ -95,15 +98,24 @@ fn create_window(
     .floating
     .centered;
 
+  let rule_geometry = manage_rule_geometry(
+    &native_window,
+    &nearest_monitor,
+    &window_state,
+    state,
+    config,
+  );
+
   let is_same_workspace = nearest_workspace.id() == target_workspace.id();
-  let floating_placement = {
-    let placement = if !is_same_workspace || prefers_centered {
-      native_window
-        .frame_position()?
-        .translate_to_center(&target_workspace.to_rect()?)
-    } else {
-      native_window.frame_position()?
-    };
+  let floating_placement = if let Some(geometry) = rule_geometry {
+    resolve_rule_geometry(&geometry, &target_workspace)?
+  } else if !is_same_workspace || prefers_centered {
+    centered_floating_placement(
+      &native_window.frame_position()?,
+      &target_workspace,
+    )?
+  } else {
+    let placement = native_window.frame_position()?;
 
     #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
     placement.clamp_size(
@@ -119,6 +131,8 @@ fn create_window(
     LengthValue::from_px(0),
   );
 
+  let is_tiling = window_state == WindowState::Tiling;
+
   let window_container: WindowContainer = match window_state {
     WindowState::Tiling => TilingWindow::new(
       None,
@@ -159,6 +173,25 @@ fn create_window(
     window_container.set_has_pending_dpi_adjustment(true);
   }
 
+  // Scrolling-tiling workspaces lay out columns rather than a BSP tree;
+  // a newly managed tiling window becomes its own new column at the
+  // right end of the strip (see `scrolling_columns::consume_window_into_column`
+  // for merging it into an existing one).
+  if is_tiling && target_workspace.config().scrolling {
+    state
+      .scrolling_layouts
+      .entry(target_workspace.id())
+      .or_default()
+      .columns
+      .push(Column {
+        windows: vec![window_container.clone()],
+      });
+  }
+
+  state.register_animation_cleanup_listener(&window_container);
+  state.register_border_overlay_cleanup_listener(&window_container);
+  state.register_scrolling_column_cleanup_listener(&window_container);
+
   Ok(window_container)
 }
 
@@ -204,102 +237,164 @@ fn window_state_to_create(
   Ok(WindowState::default_from_config(&config.value))
 }
 
-// [Modified] Dynamic Tiling Logic
-fn insertion_target(
+/// Finds the geometry declared by the first `Manage` window rule that
+/// matches `native_window`, if any.
+fn manage_rule_geometry(
+  native_window: &NativeWindow,
+  nearest_monitor: &Monitor,
   window_state: &WindowState,
-  state: &mut WmState,
+  state: &WmState,
   config: &UserConfig,
-) -> anyhow::Result<(Container, usize)> {
-  let focused_container =
-    state.focused_container().context("No focused container.")?;
-
-  let focused_workspace =
-    focused_container.workspace().context("No workspace.")?;
-
-  if *window_state == WindowState::Tiling {
-      // Hyprland-style: Check mouse position relative to focused window
-      if let Ok(focused_tiling) = focused_container.as_tiling_container() {
-          // Get mouse position
-          if let Ok(mouse_pos) = Platform::mouse_position() {
-              if let Ok(rect) = focused_tiling.to_rect() {
-                  if rect.contains_point(&mouse_pos) {
-                       let center = rect.center_point();
-                       let delta_x = (mouse_pos.x - center.x) as f32;
-                       let delta_y = (mouse_pos.y - center.y) as f32;
-                       let width = rect.width() as f32;
-                       let height = rect.height() as f32;
-
-                       // Determine desired split based on quadrant
-                       // If horizontal distance (normalized) > vertical distance (normalized) -> Horizontal split
-                       let desired_dir = if (delta_x.abs() / width) > (delta_y.abs() / height) {
-                           TilingDirection::Horizontal
-                       } else {
-                           TilingDirection::Vertical
-                       };
-                       
-                       // Determine insertion index (Before or After)
-                       let insert_after = match desired_dir {
-                           TilingDirection::Horizontal => delta_x > 0.0,
-                           TilingDirection::Vertical => delta_y > 0.0,
-                       };
-
-                       let parent = focused_tiling.parent().context("No parent")?;
-                       let current_dir = parent.tiling_direction();
-
-                       if current_dir == desired_dir {
-                           // Same direction, just insert next to it
-                           let index = focused_tiling.index();
-                           return Ok((parent, if insert_after { index + 1 } else { index }));
-                       } else {
-                           // Different direction, need to wrap focused window
-                           // If the parent only has 1 child (the focused one), we can just change the direction!
-                           if parent.child_count() == 1 {
-                                set_tiling_direction(&parent, state, config, &desired_dir)?;
-                                return Ok((parent, if insert_after { 1 } else { 0 }));
-                           } 
-
-                           // Else, wrap in new split container
-                           let split = SplitContainer::new(
-                               None,
-                               desired_dir,
-                               None,
-                               Vec::new(),
-                               None
-                           );
-                           
-                           // Wrap focused window
-                           wrap_in_split_container(
-                               &split, 
-                               &parent, 
-                               &[focused_tiling.clone()]
-                           )?;
-
-                           // Return the new split container as parent
-                           return Ok((split.into(), if insert_after { 1 } else { 0 }));
-                       }
-                  }
-              }
-          }
+) -> Option<InitialGeometryConfig> {
+  let attrs =
+    window_match_attrs(native_window, nearest_monitor, window_state, state)?;
+
+  config
+    .value
+    .window_rules
+    .iter()
+    .filter(|rule| rule.on.contains(&WindowRuleEvent::Manage))
+    .find_map(|rule| {
+      matches_window_rule(&rule.match_window, &attrs)
+        .then(|| rule.geometry.clone())
+        .flatten()
+    })
+}
+
+/// Whether `attrs` satisfies any of a rule's `match_window` entries
+/// (each entry's own fields/`any_of`/`all_of`/`none_of` are ANDed
+/// internally by `WindowMatchConfig::is_match`; entries in the slice are
+/// ORed against each other). Factored out of `manage_rule_geometry` so
+/// other rule-matching call sites (e.g. `run_window_rules`) can share
+/// the same extended-attrs matching instead of a separate flat check.
+pub(crate) fn matches_window_rule(
+  match_window: &[WindowMatchConfig],
+  attrs: &WindowMatchAttrs,
+) -> bool {
+  match_window.iter().any(|match_config| match_config.is_match(attrs))
+}
+
+/// Builds the `WindowMatchAttrs` that window rules are matched against
+/// for `native_window`, with `monitor_index`/`is_floating`/
+/// `is_fullscreen` resolved from real state rather than hardcoded -
+/// shared so that `run_window_rules`'s general rule matching (not just
+/// the `Manage`-geometry path above) sees the same attrs.
+pub(crate) fn window_match_attrs(
+  native_window: &NativeWindow,
+  nearest_monitor: &Monitor,
+  window_state: &WindowState,
+  state: &WmState,
+) -> Option<WindowMatchAttrs> {
+  let nearest_workspace = nearest_monitor.displayed_workspace()?;
+  let frame = native_window.frame_position().ok()?;
+
+  #[allow(clippy::cast_possible_truncation)]
+  let monitor_index = state
+    .monitors()
+    .iter()
+    .position(|monitor| monitor.id() == nearest_monitor.id())
+    .unwrap_or(0) as u32;
+
+  Some(WindowMatchAttrs {
+    process_name: native_window.process_name().unwrap_or_default(),
+    class_name: native_window.class_name().unwrap_or_default(),
+    title: native_window.title().unwrap_or_default(),
+    monitor_index,
+    monitor_name: nearest_monitor.native().device_name().unwrap_or_default(),
+    workspace_name: nearest_workspace.config().name.clone(),
+    is_floating: matches!(window_state, WindowState::Floating(_)),
+    is_fullscreen: matches!(window_state, WindowState::Fullscreen(_)),
+    width: frame.width(),
+    height: frame.height(),
+  })
+}
+
+/// Resolves a rule-declared `InitialGeometryConfig` into an absolute
+/// rect on `workspace`, anchoring unset `x`/`y`/`width`/`height` to the
+/// workspace's own position/size.
+fn resolve_rule_geometry(
+  geometry: &InitialGeometryConfig,
+  workspace: &crate::models::Workspace,
+) -> anyhow::Result<wm_common::Rect> {
+  let workspace_rect = workspace.to_rect()?;
+
+  let width = geometry
+    .width
+    .as_ref()
+    .map_or(workspace_rect.width(), |value| {
+      value.to_px(workspace_rect.width())
+    });
+
+  let height = geometry
+    .height
+    .as_ref()
+    .map_or(workspace_rect.height(), |value| {
+      value.to_px(workspace_rect.height())
+    });
+
+  let (default_x, default_y) = match geometry.anchor {
+    GeometryAnchor::Center => (
+      workspace_rect.x() + (workspace_rect.width() - width) / 2,
+      workspace_rect.y() + (workspace_rect.height() - height) / 2,
+    ),
+    GeometryAnchor::TopLeft => (workspace_rect.x(), workspace_rect.y()),
+    GeometryAnchor::TopRight => {
+      (workspace_rect.x() + workspace_rect.width() - width, workspace_rect.y())
+    }
+    GeometryAnchor::BottomLeft => {
+      (workspace_rect.x(), workspace_rect.y() + workspace_rect.height() - height)
+    }
+    GeometryAnchor::BottomRight => (
+      workspace_rect.x() + workspace_rect.width() - width,
+      workspace_rect.y() + workspace_rect.height() - height,
+    ),
+  };
+
+  // An explicit `x`/`y` offset is measured inward from whichever edge
+  // `anchor` pins to, matching `default_x`/`default_y` above - a
+  // `BottomRight`-anchored rule's `x` nudges left from the right edge,
+  // not right from the workspace's left edge.
+  let x = geometry.x.as_ref().map_or(default_x, |value| {
+    let offset = value.to_px(workspace_rect.width());
+    match geometry.anchor {
+      GeometryAnchor::TopRight | GeometryAnchor::BottomRight => {
+        workspace_rect.x() + workspace_rect.width() - width - offset
       }
+      _ => workspace_rect.x() + offset,
+    }
+  });
 
-    // Fallback logic
-    let sibling = match focused_container {
-      Container::TilingWindow(_) => Some(focused_container),
-      _ => focused_workspace
-        .descendant_focus_order()
-        .find(Container::is_tiling_window),
-    };
-
-    if let Some(sibling) = sibling {
-      return Ok((
-        sibling.parent().context("No parent.")?,
-        sibling.index() + 1,
-      ));
+  let y = geometry.y.as_ref().map_or(default_y, |value| {
+    let offset = value.to_px(workspace_rect.height());
+    match geometry.anchor {
+      GeometryAnchor::BottomLeft | GeometryAnchor::BottomRight => {
+        workspace_rect.y() + workspace_rect.height() - height - offset
+      }
+      _ => workspace_rect.y() + offset,
     }
-  }
+  });
 
-  Ok((
-    focused_workspace.clone().into(),
-    focused_workspace.child_count(),
-  ))
+  Ok(wm_common::Rect::from_xy(x, y, width, height))
 }
+
+/// Centers `frame` on `workspace` and clamps it to 90% of the
+/// workspace's size. Shared by initial window placement and the
+/// scratchpad summon command, which both need to drop an arbitrary
+/// window frame into the middle of a workspace.
+pub(crate) fn centered_floating_placement(
+  frame: &wm_common::Rect,
+  workspace: &crate::models::Workspace,
+) -> anyhow::Result<wm_common::Rect> {
+  let workspace_rect = workspace.to_rect()?;
+
+  #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+  let placement = frame
+    .translate_to_center(&workspace_rect)
+    .clamp_size(
+      (workspace_rect.width() as f32 * 0.9) as i32,
+      (workspace_rect.height() as f32 * 0.9) as i32,
+    );
+
+  Ok(placement)
+}
+
@@ -0,0 +1,117 @@
+use anyhow::{bail, Context};
+use tracing::info;
+use wm_common::{DisplayState, WindowState, WmEvent};
+
+use crate::{
+  commands::{
+    container::{detach_container, set_focused_descendant},
+    window::centered_floating_placement,
+  },
+  models::WindowContainer,
+  traits::{CommonGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// `move-to-scratchpad`: detaches `window` from its workspace into the
+/// named scratchpad, hiding its native window via the configured
+/// `HideMethod`. The window stays alive (unlike a close) and can later
+/// be brought back with [`toggle_scratchpad`].
+///
+/// Windows whose `Manage` rule assigns them to a scratchpad at startup
+/// go through this same path from `WmState::populate`, so they start
+/// out hidden rather than flashing on screen before being stashed.
+pub fn move_to_scratchpad(
+  name: &str,
+  window: WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let workspace = window.workspace().context("No workspace.")?;
+
+  detach_container(&window.clone().into())?;
+
+  window.set_display_state(DisplayState::Hiding);
+  window.native().set_visible(false, &config.value.general.hide_method)?;
+
+  // A window can only be parked under one scratchpad name at a time -
+  // drop any other name it was previously assigned to, so `scratchpads`
+  // can't end up with two names both resolving to the same window (one
+  // of which `toggle_scratchpad` would summon to a stale expectation).
+  state
+    .scratchpads
+    .retain(|_, existing| existing.id() != window.id());
+
+  state.scratchpads.insert(name.to_string(), window.clone());
+
+  if !state.scratchpad.iter().any(|w| w.id() == window.id()) {
+    state.scratchpad.push(window.clone());
+  }
+
+  state.pending_sync.queue_workspace_to_reorder(workspace);
+  state.pending_sync.queue_focus_change();
+
+  state.emit_event(WmEvent::WindowUnmanaged {
+    unmanaged_id: window.id(),
+    unmanaged_handle: window.native().handle,
+  });
+
+  Ok(())
+}
+
+/// `toggle-scratchpad <name>`: summons the scratchpad named `name` as a
+/// centered floating window on the currently focused monitor's
+/// workspace, or re-hides it if it's already shown. Errors if nothing
+/// has ever been assigned to that scratchpad.
+pub fn toggle_scratchpad(
+  name: &str,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let Some(window) = state.scratchpads.get(name).cloned() else {
+    bail!("No window assigned to scratchpad '{name}'.");
+  };
+
+  if window.display_state() == DisplayState::Shown
+    || window.display_state() == DisplayState::Showing
+  {
+    return move_to_scratchpad(name, window, state, config);
+  }
+
+  let focused_workspace = state
+    .focused_container()
+    .context("No focused container.")?
+    .workspace()
+    .context("No focused workspace.")?;
+
+  let placement = centered_floating_placement(
+    &window.to_rect()?,
+    &focused_workspace,
+  )?;
+
+  window.set_floating_placement(placement);
+  window.set_state(WindowState::Floating(
+    config.value.window_behavior.state_defaults.floating.clone(),
+  ));
+
+  crate::commands::container::attach_container(
+    &window.clone().into(),
+    &focused_workspace.clone().into(),
+    None,
+  )?;
+
+  window.set_display_state(DisplayState::Showing);
+  window.native().set_visible(true, &config.value.general.hide_method)?;
+
+  state.scratchpad.retain(|w| w.id() != window.id());
+
+  set_focused_descendant(&window.clone().into(), None);
+
+  info!("Summoned scratchpad '{name}': {window}");
+
+  state.pending_sync.queue_container_to_redraw(window.into());
+  state.pending_sync.queue_focus_change();
+  state.pending_sync.queue_focused_effect_update();
+
+  Ok(())
+}
@@ -0,0 +1,145 @@
+use anyhow::Context;
+
+use crate::{
+  commands::container::set_focused_descendant,
+  models::{scrolling_layout::ScrollingLayout, Workspace},
+  traits::{CommonGetters, PositionGetters},
+  wm_state::WmState,
+};
+
+/// Direction to move focus or a column within a scrolling workspace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnDirection {
+  Left,
+  Right,
+}
+
+/// `focus-column-left`/`focus-column-right`: moves focus to the
+/// adjacent column (if any) and scrolls the viewport to keep it fully
+/// visible.
+pub fn focus_column(
+  workspace: &Workspace,
+  direction: ColumnDirection,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let Some(layout) = state.scrolling_layouts.get_mut(&workspace.id()) else {
+    return Ok(());
+  };
+
+  let focused_id = state
+    .focused_container()
+    .context("No focused container.")?
+    .id();
+
+  let Some(current_index) = layout.column_index_of(focused_id) else {
+    return Ok(());
+  };
+
+  let target_index = match direction {
+    ColumnDirection::Left => current_index.checked_sub(1),
+    ColumnDirection::Right => (current_index + 1 < layout.columns.len())
+      .then_some(current_index + 1),
+  };
+
+  let Some(target_index) = target_index else {
+    return Ok(());
+  };
+
+  let Some(target_window) = layout.columns[target_index].windows.first().cloned()
+  else {
+    return Ok(());
+  };
+
+  let workspace_rect = workspace.to_rect()?;
+  let viewport_width = workspace_rect.width();
+  let column_width = ScrollingLayout::column_width(&workspace_rect);
+  layout.scroll_to_column(target_window.id(), viewport_width, column_width);
+
+  set_focused_descendant(&target_window.into(), None);
+  state.pending_sync.queue_focus_change();
+  state
+    .pending_sync
+    .queue_container_to_redraw(workspace.clone().into());
+
+  Ok(())
+}
+
+/// `move-column-left`/`move-column-right`: swaps the focused window's
+/// column with the adjacent one.
+pub fn move_column(
+  workspace: &Workspace,
+  direction: ColumnDirection,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let Some(layout) = state.scrolling_layouts.get_mut(&workspace.id()) else {
+    return Ok(());
+  };
+
+  let focused_id = state
+    .focused_container()
+    .context("No focused container.")?
+    .id();
+
+  let Some(current_index) = layout.column_index_of(focused_id) else {
+    return Ok(());
+  };
+
+  let target_index = match direction {
+    ColumnDirection::Left => current_index.checked_sub(1),
+    ColumnDirection::Right => (current_index + 1 < layout.columns.len())
+      .then_some(current_index + 1),
+  };
+
+  let Some(target_index) = target_index else {
+    return Ok(());
+  };
+
+  layout.columns.swap(current_index, target_index);
+
+  let workspace_rect = workspace.to_rect()?;
+  let viewport_width = workspace_rect.width();
+  let column_width = ScrollingLayout::column_width(&workspace_rect);
+  layout.scroll_to_column(focused_id, viewport_width, column_width);
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(workspace.clone().into());
+
+  Ok(())
+}
+
+/// `consume-window-into-column`: moves every window of the column to
+/// the right of the focused window's column into the focused column, as
+/// new rows below its existing ones. Does nothing when the focused
+/// column is the rightmost one.
+pub fn consume_window_into_column(
+  workspace: &Workspace,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let focused_id = state
+    .focused_container()
+    .context("No focused container.")?
+    .id();
+
+  let Some(layout) = state.scrolling_layouts.get_mut(&workspace.id()) else {
+    return Ok(());
+  };
+
+  let Some(current_index) = layout.column_index_of(focused_id) else {
+    return Ok(());
+  };
+
+  // Consume the next column over, if any.
+  if current_index + 1 >= layout.columns.len() {
+    return Ok(());
+  }
+
+  let mut consumed = layout.columns.remove(current_index + 1);
+  layout.columns[current_index].windows.append(&mut consumed.windows);
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(workspace.clone().into());
+
+  Ok(())
+}
@@ -1,121 +1,61 @@
+use std::time::{Duration, Instant};
+
 use anyhow::Context;
-use wm_platform::{MouseMoveEvent, Platform, NativeWindow};
-use wm_common::{Point, WindowState, InvokeCommand};
-use windows::Win32::UI::Input::KeyboardAndMouse::{GetAsyncKeyState, VK_MENU, VK_LBUTTON};
+use wm_common::{
+  MouseBindingsConfig, MouseClickAction, MouseDragAction, MouseModifier,
+  Point, Rect, WindowState,
+};
+use wm_platform::{MouseMoveEvent, Platform};
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+  GetAsyncKeyState, VK_CONTROL, VK_LBUTTON, VK_LWIN, VK_MBUTTON, VK_MENU,
+  VK_RBUTTON, VK_SHIFT,
+};
 
 use crate::{
   commands::{
-      container::set_focused_descendant, 
-      window::{set_window_position, update_window_state, WindowPositionTarget},
+    container::set_focused_descendant,
+    window::{
+      drag_to_tile::drop_dragged_window, set_window_position,
+      update_window_state, WindowPositionTarget,
+    },
   },
-  models::{Container, WindowContainer},
+  models::WindowContainer,
   traits::{CommonGetters, PositionGetters, WindowGetters},
-  user_config::UserConfig, 
-  wm_state::{WmState, DragState},
+  user_config::UserConfig,
+  wm_state::{DragAction, DragState, ResizeEdges, WmState},
 };
 
+/// Maximum distance (in pixels) and time between two presses for them to
+/// count as a `MouseBindingsConfig.double_click`.
+const DOUBLE_CLICK_DISTANCE: i32 = 4;
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Minimum cursor movement (in pixels) from `DragState.grab_point`
+/// before a press counts as an actual drag rather than a click - see
+/// `continue_drag`.
+const DRAG_MOVE_THRESHOLD: i32 = 4;
+
+/// Smallest width/height a `DragAction::Resize` is allowed to shrink a
+/// window's floating placement to, so dragging an edge past its
+/// opposite one can't invert or collapse the rect - see `continue_drag`.
+const MIN_RESIZE_SIZE: i32 = 50;
+
 pub fn handle_mouse_move(
   event: &MouseMoveEvent,
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<()> {
-  
-  // [Added] Alt + Drag Implementation
-  // Check if Alt is held down (high bit set if key is down)
-  let alt_down = unsafe { GetAsyncKeyState(VK_MENU.0 as i32) } as i16 & 0x8000 != 0;
-  
-  // Initialize drag state if Alt+Click just started
-  if alt_down && event.is_mouse_down && state.drag_state.is_none() {
-      if let Ok(window) = Platform::window_from_point(&event.point)
-        .and_then(|w| Platform::root_ancestor(&w)) 
-        .map(|root| state.window_from_native(&root)) 
-        .transpose() 
-      {
-         if let Some(win_container) = window {
-             state.drag_state = Some(DragState {
-                 start_point: event.point,
-                 window_id: win_container.id(),
-                 is_dragging: true
-             });
-             
-             // If tiling, float it immediately to allow dragging
-             if matches!(win_container.state(), WindowState::Tiling) {
-                 update_window_state(
-                     win_container, 
-                     WindowState::Floating(config.value.window_behavior.state_defaults.floating.clone()), 
-                     state, 
-                     config
-                 )?;
-             }
-         }
-      }
+  let bindings = &config.value.mouse_bindings;
+
+  if state.drag_state.is_none() {
+    try_start_drag(event, bindings, state, config)?;
   }
 
-  // Handle Dragging
-  if let Some(drag_state) = &state.drag_state {
-      if !event.is_mouse_down {
-          // Mouse released, clear drag state
-          state.drag_state = None;
-      } else {
-          // Dragging logic
-          if let Some(container) = state.container_by_id(drag_state.window_id) {
-              if let Ok(window) = container.as_window_container() {
-                   // Calculate delta
-                   // Since we receive absolute points, we can just update position
-                   // Note: We might want smoother delta tracking, but setting absolute pos follows cursor best
-                   
-                   // Get current window rect to calculate offset from original click
-                   // A simpler approach for "Move" is just centering the window on cursor or maintaining offset
-                   // For now, let's just move the window based on delta from *last* event, 
-                   // but we only have current event point.
-                   
-                   // We need the window's current position to apply the delta from the *previous* frame.
-                   // But `event.point` is absolute. 
-                   // Let's rely on standard logic: Current Pos = Window Pos + (Current Mouse - Last Mouse)
-                   // Since we don't store "Last Mouse" easily without more state, 
-                   // we can just set the window position to (Mouse Pos - Offset).
-                   // Let's assume user wants to drag from the clicked point.
-                   
-                   // Implementation:
-                   // 1. Get window rect.
-                   // 2. We don't have the initial offset stored in DragState. 
-                   //    Let's stick to a simple "follow cursor" or rely on the user dragging.
-                   //    Actually, standard drag is: NewWinPos = OldWinPos + (MouseDelta).
-                   //    We need `last_mouse_pos`.
-                   
-                   // Hack: We can use the drag_state.start_point, but that snaps window to start.
-                   // Better: Use static or store last_pos in DragState.
-                   // Since I can't easily change DragState definition *again* without rewriting wm_state, 
-                   // let's assume we update start_point every frame.
-              }
-          }
-          
-          // Update start point for next delta
-          if let Some(ds) = &mut state.drag_state {
-               let delta_x = event.point.x - ds.start_point.x;
-               let delta_y = event.point.y - ds.start_point.y;
-               
-               if let Some(container) = state.container_by_id(ds.window_id) {
-                   if let Ok(window) = container.as_window_container() {
-                       if let Ok(rect) = window.to_rect() {
-                           let new_x = rect.x() + delta_x;
-                           let new_y = rect.y() + delta_y;
-                           
-                           set_window_position(
-                               window, 
-                               &WindowPositionTarget::Coordinates(new_x, new_y), 
-                               state
-                           )?;
-                       }
-                   }
-               }
-               ds.start_point = event.point;
-          }
-          return Ok(()); // Swallow event if dragging
-      }
+  if state.drag_state.is_some() {
+    return continue_drag(event, state, config);
   }
 
-  // Original Logic
+  // Original focus-follows-cursor logic.
   if event.is_mouse_down
     || !state.is_focus_synced
     || !config.value.general.focus_follows_cursor
@@ -154,3 +94,294 @@ pub fn handle_mouse_move(
 
   Ok(())
 }
+
+/// Checks whether a new modifier+button drag should start under the
+/// cursor, consulting `MouseBindingsConfig` for which button maps to
+/// which action. A rising edge on whichever button starts a drag also
+/// doubles as a double-click check for `MouseBindingsConfig.double_click`.
+fn try_start_drag(
+  event: &MouseMoveEvent,
+  bindings: &MouseBindingsConfig,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if !event.is_mouse_down || !is_modifier_down(bindings.modifier) {
+    return Ok(());
+  }
+
+  let Some(action) = pressed_button_action(bindings) else {
+    return Ok(());
+  };
+
+  if is_double_click(event.point, state) {
+    if let Some(window) = window_under_point(event.point, state)? {
+      if bindings.double_click == MouseClickAction::ToggleFloating {
+        toggle_floating(window, state, config)?;
+      }
+    }
+
+    return Ok(());
+  }
+
+  let Some(window) = window_under_point(event.point, state)? else {
+    return Ok(());
+  };
+
+  let grab_rect = window.to_rect()?;
+
+  let drag_action = match action {
+    MouseDragAction::None => return Ok(()),
+    MouseDragAction::Move => DragAction::Move,
+    MouseDragAction::Resize => {
+      let edges = resize_edges_for_grab(&grab_rect, &event.point);
+
+      if edges.is_none() {
+        DragAction::Move
+      } else {
+        DragAction::Resize(edges)
+      }
+    }
+  };
+
+  state.drag_state = Some(DragState {
+    grab_point: event.point,
+    grab_rect,
+    window_id: window.id(),
+    action: drag_action,
+    has_moved: false,
+  });
+
+  if let Some(bounds) = state.monitors_bounding_rect() {
+    _ = Platform::confine_cursor(&bounds);
+  }
+
+  // Floating a tiling window happens once the cursor actually crosses
+  // `DRAG_MOVE_THRESHOLD` in `continue_drag`, not here - a press and
+  // release with no movement in between (e.g. an accidental click while
+  // holding the drag modifier) should leave the window exactly as it
+  // was instead of permanently floating it.
+  Ok(())
+}
+
+/// Applies the total delta of an in-progress drag against the fixed
+/// `grab_point`/`grab_rect` captured at drag start (never against the
+/// previous frame), either moving the whole window or resizing only the
+/// edges grabbed at drag start. On release, clears `state.drag_state`,
+/// releases the cursor confinement, and - for a `DragAction::Move` -
+/// hands off to `drop_dragged_window` to hit-test a drop-to-tile target.
+fn continue_drag(
+  event: &MouseMoveEvent,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if !event.is_mouse_down {
+    let drag_state = state.drag_state.take();
+    Platform::release_cursor_confinement();
+
+    if let Some(drag_state) = drag_state {
+      if drag_state.has_moved && drag_state.action == DragAction::Move {
+        if let Some(container) = state.container_by_id(drag_state.window_id) {
+          if let Ok(window) = container.as_window_container() {
+            drop_dragged_window(window, event.point, state, config)?;
+          }
+        }
+      }
+    }
+
+    return Ok(());
+  }
+
+  let Some(drag_state) = &state.drag_state else {
+    return Ok(());
+  };
+
+  let delta_x = event.point.x - drag_state.grab_point.x;
+  let delta_y = event.point.y - drag_state.grab_point.y;
+  let window_id = drag_state.window_id;
+  let action = drag_state.action;
+  let grab_rect = drag_state.grab_rect.clone();
+  let has_moved = drag_state.has_moved;
+
+  if !has_moved {
+    if delta_x.abs() < DRAG_MOVE_THRESHOLD && delta_y.abs() < DRAG_MOVE_THRESHOLD
+    {
+      return Ok(());
+    }
+
+    if let Some(drag_state) = &mut state.drag_state {
+      drag_state.has_moved = true;
+    }
+
+    // Now that the cursor has actually moved, float the window so it
+    // can be dragged freely; tiling windows don't have a free-form
+    // position/size otherwise.
+    if let Some(container) = state.container_by_id(window_id) {
+      if let Ok(window) = container.as_window_container() {
+        if matches!(window.state(), WindowState::Tiling) {
+          update_window_state(
+            window,
+            WindowState::Floating(
+              config.value.window_behavior.state_defaults.floating.clone(),
+            ),
+            state,
+            config,
+          )?;
+        }
+      }
+    }
+  }
+
+  if let Some(container) = state.container_by_id(window_id) {
+    if let Ok(window) = container.as_window_container() {
+      match action {
+        DragAction::Move => {
+          set_window_position(
+            window,
+            &WindowPositionTarget::Coordinates(
+              grab_rect.x() + delta_x,
+              grab_rect.y() + delta_y,
+            ),
+            state,
+          )?;
+        }
+        DragAction::Resize(edges) => {
+          let mut left = grab_rect.left;
+          let mut top = grab_rect.top;
+          let mut right = grab_rect.right;
+          let mut bottom = grab_rect.bottom;
+
+          if edges.left {
+            left += delta_x;
+          }
+          if edges.right {
+            right += delta_x;
+          }
+          if edges.top {
+            top += delta_y;
+          }
+          if edges.bottom {
+            bottom += delta_y;
+          }
+
+          // Clamp so a dragged edge can't cross (or get too close to)
+          // its opposite one, which would otherwise invert or collapse
+          // the floating placement into a degenerate rect.
+          if edges.left {
+            left = left.min(right - MIN_RESIZE_SIZE);
+          }
+          if edges.right {
+            right = right.max(left + MIN_RESIZE_SIZE);
+          }
+          if edges.top {
+            top = top.min(bottom - MIN_RESIZE_SIZE);
+          }
+          if edges.bottom {
+            bottom = bottom.max(top + MIN_RESIZE_SIZE);
+          }
+
+          window.set_floating_placement(Rect::from_ltrb(
+            left, top, right, bottom,
+          ));
+          state
+            .pending_sync
+            .queue_container_to_redraw(window.clone().into());
+        }
+      }
+    }
+  }
+
+  Ok(())
+}
+
+fn window_under_point(
+  point: Point,
+  state: &WmState,
+) -> anyhow::Result<Option<WindowContainer>> {
+  Platform::window_from_point(&point)
+    .and_then(|window| Platform::root_ancestor(&window))
+    .map(|root| state.window_from_native(&root))
+}
+
+fn toggle_floating(
+  window: WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let target_state = match window.state() {
+    WindowState::Tiling => WindowState::Floating(
+      config.value.window_behavior.state_defaults.floating.clone(),
+    ),
+    _ => WindowState::Tiling,
+  };
+
+  update_window_state(window, target_state, state, config)
+}
+
+/// Splits `rect` into a 3x3 grid and returns which edges the cursor's
+/// cell should move: corners move the two edges they touch, the
+/// outer-row/column cells move a single edge, and the center cell moves
+/// none (the caller falls back to a plain move in that case).
+fn resize_edges_for_grab(rect: &Rect, point: &Point) -> ResizeEdges {
+  let third_width = (rect.right - rect.left) / 3;
+  let third_height = (rect.bottom - rect.top) / 3;
+
+  ResizeEdges {
+    left: point.x < rect.left + third_width,
+    right: point.x > rect.right - third_width,
+    top: point.y < rect.top + third_height,
+    bottom: point.y > rect.bottom - third_height,
+  }
+}
+
+fn pressed_button_action(
+  bindings: &MouseBindingsConfig,
+) -> Option<MouseDragAction> {
+  if is_vk_down(VK_LBUTTON.0) {
+    return Some(bindings.left_drag);
+  }
+
+  if is_vk_down(VK_RBUTTON.0) {
+    return Some(bindings.right_drag);
+  }
+
+  if is_vk_down(VK_MBUTTON.0) {
+    return Some(bindings.middle_drag);
+  }
+
+  None
+}
+
+fn is_modifier_down(modifier: MouseModifier) -> bool {
+  let vk = match modifier {
+    MouseModifier::Alt => VK_MENU.0,
+    MouseModifier::Super => VK_LWIN.0,
+    MouseModifier::Shift => VK_SHIFT.0,
+    MouseModifier::Ctrl => VK_CONTROL.0,
+  };
+
+  is_vk_down(vk)
+}
+
+fn is_vk_down(vk: u16) -> bool {
+  unsafe { GetAsyncKeyState(i32::from(vk)) < 0 }
+}
+
+/// Tracks `state.last_mouse_press` to recognize a second press on
+/// roughly the same spot within `DOUBLE_CLICK_INTERVAL` as a double
+/// click, rather than a fresh drag start.
+fn is_double_click(point: Point, state: &mut WmState) -> bool {
+  let now = Instant::now();
+
+  let is_double_click = state.last_mouse_press.is_some_and(
+    |(last_time, last_point)| {
+      now.duration_since(last_time) <= DOUBLE_CLICK_INTERVAL
+        && (point.x - last_point.x).abs() <= DOUBLE_CLICK_DISTANCE
+        && (point.y - last_point.y).abs() <= DOUBLE_CLICK_DISTANCE
+    },
+  );
+
+  state.last_mouse_press =
+    if is_double_click { None } else { Some((now, point)) };
+
+  is_double_click
+}
@@ -0,0 +1,123 @@
+use uuid::Uuid;
+use wm_common::Rect;
+
+use crate::models::WindowContainer;
+
+/// A single column in a [`ScrollingLayout`]'s infinite horizontal strip.
+/// Each column occupies the full workspace height, split evenly among
+/// the windows it contains (top-to-bottom in insertion order).
+#[derive(Clone, Debug, Default)]
+pub struct Column {
+  pub windows: Vec<WindowContainer>,
+}
+
+/// PaperWM/niri-style scrolling-tiling state for a single workspace:
+/// columns extend rightward without bound and only a viewport-width
+/// slice is mapped on screen. Lives per-workspace (see
+/// `WmState::scrolling_layouts`), never globally, so a monitor's strip
+/// can't bleed onto an adjacent monitor.
+#[derive(Clone, Debug, Default)]
+pub struct ScrollingLayout {
+  pub viewport_x: f32,
+  pub columns: Vec<Column>,
+}
+
+impl ScrollingLayout {
+  /// Width a column occupies on screen, given the workspace it belongs
+  /// to. Columns are currently always exactly one viewport-width wide
+  /// (so scrolling pages between them one at a time, like a maximized
+  /// window per column, rather than showing several narrower columns
+  /// side by side); this is the single place that policy lives, so a
+  /// future content-sized or configurable column width only needs to
+  /// change here instead of at each of this layout's three call sites
+  /// (`platform_sync::scrolling_column_rect`, `focus_column`,
+  /// `move_column`).
+  pub fn column_width(workspace_rect: &Rect) -> i32 {
+    workspace_rect.width()
+  }
+
+  /// x-offset of each column's left edge, accumulated left-to-right.
+  fn column_offsets(&self, column_width: i32) -> Vec<i32> {
+    (0..self.columns.len())
+      .map(|index| {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = index as i32;
+        index * column_width
+      })
+      .collect()
+  }
+
+  /// Rect for `window_id` within this layout, relative to
+  /// `workspace_rect` and the current scroll offset, or `None` if it
+  /// isn't a member of any column.
+  #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+  pub fn window_rect(
+    &self,
+    window_id: Uuid,
+    workspace_rect: &Rect,
+    column_width: i32,
+  ) -> Option<Rect> {
+    let offsets = self.column_offsets(column_width);
+
+    self.columns.iter().zip(offsets).find_map(|(column, x_offset)| {
+      let row = column
+        .windows
+        .iter()
+        .position(|window| window.id() == window_id)?;
+
+      let row_height = workspace_rect.height() / column.windows.len() as i32;
+
+      let x = workspace_rect.x() + x_offset - self.viewport_x as i32;
+      let y = workspace_rect.y() + row_height * row as i32;
+
+      Some(Rect::from_xy(x, y, column_width, row_height))
+    })
+  }
+
+  /// Whether a window's computed rect lies entirely outside the
+  /// monitor rect and should therefore be positioned off-screen rather
+  /// than hidden, so it can animate back in smoothly once scrolled into
+  /// view.
+  pub fn is_rect_offscreen(rect: &Rect, monitor_rect: &Rect) -> bool {
+    !rect.has_overlap_x(monitor_rect) || !rect.has_overlap_y(monitor_rect)
+  }
+
+  /// Scrolls the viewport so the column containing `window_id` is fully
+  /// visible: the viewport stays put if it already is, and otherwise
+  /// moves just far enough to bring the column flush with the nearer
+  /// viewport edge. The column is never centered.
+  #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+  pub fn scroll_to_column(
+    &mut self,
+    window_id: Uuid,
+    viewport_width: i32,
+    column_width: i32,
+  ) {
+    let offsets = self.column_offsets(column_width);
+
+    let Some(column_index) = self.columns.iter().position(|column| {
+      column.windows.iter().any(|window| window.id() == window_id)
+    }) else {
+      return;
+    };
+
+    let column_x = offsets[column_index];
+    let column_right = column_x + column_width;
+    let viewport_left = self.viewport_x as i32;
+    let viewport_right = viewport_left + viewport_width;
+
+    if column_x < viewport_left {
+      self.viewport_x = column_x as f32;
+    } else if column_right > viewport_right {
+      self.viewport_x = (column_right - viewport_width) as f32;
+    }
+  }
+
+  /// Index of the column containing `window_id`, if any.
+  pub fn column_index_of(&self, window_id: Uuid) -> Option<usize> {
+    self
+      .columns
+      .iter()
+      .position(|column| column.windows.iter().any(|w| w.id() == window_id))
+  }
+}
@@ -1,5 +1,5 @@
-use std::time::Instant;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use anyhow::Context;
 use tokio::sync::mpsc::{self};
@@ -7,14 +7,17 @@ use tokio::task::JoinHandle;
 use tracing::warn;
 use uuid::Uuid;
 use wm_common::{
-  BindingModeConfig, Direction, Point, WindowState, WmEvent,
+  BindingModeConfig, Direction, EasingConfig, Point, Rect, WindowState,
+  WmEvent,
 };
 use wm_platform::{NativeMonitor, NativeWindow, Platform};
 
 use crate::{
   commands::{
-    container::set_focused_descendant, general::platform_sync,
-    monitor::add_monitor, window::manage_window,
+    container::set_focused_descendant,
+    general::{platform_sync, watch_config::start_config_watcher},
+    monitor::add_monitor,
+    window::manage_window,
   },
   models::{
     Container, Monitor, RootContainer, WindowContainer, Workspace,
@@ -25,10 +28,228 @@ use crate::{
   user_config::UserConfig,
 };
 
-// [Modified] State for Alt+Drag operation
+/// State for an in-progress modifier+mouse drag, started and driven by
+/// `events::handle_mouse_move` per `ParsedConfig.mouse_bindings`.
+///
+/// Deltas are always computed against the fixed `grab_point`/`grab_rect`
+/// captured at drag start, rather than accumulated frame-to-frame, so
+/// the window stays pinned exactly under the grab point regardless of
+/// event coalescing (accumulating per-frame deltas drifts on fast
+/// moves, since coalesced move events skip intermediate positions).
 pub struct DragState {
-    pub start_point: Point,
-    pub window_id: Uuid,
+  /// Cursor position at the moment the drag started.
+  pub grab_point: Point,
+  /// The window's own frame rect at the moment the drag started.
+  pub grab_rect: Rect,
+  pub window_id: Uuid,
+  pub action: DragAction,
+  /// Whether the cursor has moved past `handle_mouse_move`'s drag
+  /// threshold yet. A press+release with no real movement in between
+  /// (e.g. an accidental click while holding the drag modifier) should
+  /// leave the window exactly as it was, not float a tiling window that
+  /// never actually got dragged anywhere.
+  pub has_moved: bool,
+}
+
+/// What a drag does to its target window as the cursor moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DragAction {
+  Move,
+  Resize(ResizeEdges),
+}
+
+/// Which edges of a window's frame a grabbed resize drag should move,
+/// determined by which cell of a 3x3 grid the cursor grabbed at drag
+/// start (corners move two edges, edges move one).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResizeEdges {
+  pub left: bool,
+  pub right: bool,
+  pub top: bool,
+  pub bottom: bool,
+}
+
+impl ResizeEdges {
+  #[must_use]
+  pub fn is_none(&self) -> bool {
+    !self.left && !self.right && !self.top && !self.bottom
+  }
+}
+
+/// In-flight frame interpolation for a single window, keyed by native
+/// window handle in [`WmState::animations`].
+///
+/// `to` is the authoritative target rect queued by the most recent
+/// redraw; `from`/`start` capture where the animation began so that a
+/// retargeted animation can rebase smoothly instead of jumping.
+pub struct WindowAnimation {
+  pub from: Rect,
+  pub to: Rect,
+  pub start: Instant,
+  pub duration: Duration,
+  pub easing: EasingConfig,
+
+  /// `Some(true)`/`Some(false)` while this animation is also fading
+  /// opacity in/out for a display-state transition, `None` for a plain
+  /// position/size move. Lets a retargeted fade rebase its starting
+  /// alpha from wherever the aborted fade actually got to, instead of
+  /// always restarting from fully transparent/opaque (see
+  /// `platform_sync::redraw_containers`).
+  pub fade: Option<bool>,
+}
+
+impl WindowAnimation {
+  /// Interpolated rect at the current instant, eased per `self.easing`.
+  ///
+  /// Not meaningful for `EasingConfig::Spring`: a spring's position
+  /// isn't a pure function of elapsed time, it's integrated tick by
+  /// tick by the animation task itself, so `redraw_containers` reads
+  /// `NativeWindow::frame_position` instead of this method when
+  /// rebasing a retargeted spring animation.
+  pub fn current_rect(&self) -> Rect {
+    let elapsed = self.start.elapsed().as_secs_f32();
+    let duration = self.duration.as_secs_f32().max(f32::EPSILON);
+    let t = (elapsed / duration).clamp(0.0, 1.0);
+
+    lerp_rect(&self.from, &self.to, ease(&self.easing, t))
+  }
+
+  /// Interpolated opacity (0-255) at the current instant, for a fade
+  /// that's still in flight. `None` if this animation isn't fading.
+  /// Unlike `current_rect`, opacity always rides a plain linear `t` (see
+  /// `apply_fade_tick`), independent of `self.easing`.
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  pub fn current_alpha(&self) -> Option<u8> {
+    let fading_in = self.fade?;
+
+    let elapsed = self.start.elapsed().as_secs_f32();
+    let duration = self.duration.as_secs_f32().max(f32::EPSILON);
+    let t = (elapsed / duration).clamp(0.0, 1.0);
+
+    Some(if fading_in { t * 255.0 } else { (1.0 - t) * 255.0 } as u8)
+  }
+}
+
+/// Component-wise linear interpolation between two rects.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+pub fn lerp_rect(from: &Rect, to: &Rect, t: f32) -> Rect {
+  Rect::from_ltrb(
+    (from.left as f32 + (to.left - from.left) as f32 * t) as i32,
+    (from.top as f32 + (to.top - from.top) as f32 * t) as i32,
+    (from.right as f32 + (to.right - from.right) as f32 * t) as i32,
+    (from.bottom as f32 + (to.bottom - from.bottom) as f32 * t) as i32,
+  )
+}
+
+/// Maps a normalized `0..1` animation progress through `easing`.
+/// `Spring` has no closed-form curve over `t` - callers driving a
+/// spring animation should use `step_spring` instead, so this only
+/// exists to give `WindowAnimation::current_rect` a sane (if unused in
+/// practice) fallback.
+pub fn ease(easing: &EasingConfig, t: f32) -> f32 {
+  match *easing {
+    EasingConfig::Linear | EasingConfig::Spring { .. } => t,
+    EasingConfig::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+    EasingConfig::EaseInOutQuad => {
+      if t < 0.5 {
+        2.0 * t * t
+      } else {
+        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+      }
+    }
+    EasingConfig::EaseOutBack => {
+      const OVERSHOOT: f32 = 1.70158;
+      const C3: f32 = OVERSHOOT + 1.0;
+
+      1.0 + C3 * (t - 1.0).powi(3) + OVERSHOOT * (t - 1.0).powi(2)
+    }
+  }
+}
+
+/// One rect's worth of scalar state (left, top, right, bottom), used by
+/// `step_spring` to integrate position and velocity per edge.
+pub type RectScalars = (f32, f32, f32, f32);
+
+/// Epsilon (in pixels / pixels-per-second) below which a spring's
+/// displacement and velocity on every edge both count as settled.
+const SPRING_SETTLE_EPSILON: f32 = 0.5;
+
+/// Advances one critically-damped-ish spring step per edge:
+/// `v += (target - pos) * stiffness * dt; v *= damping; pos += v * dt`.
+/// Mutates `pos`/`vel` in place and returns `true` once every edge has
+/// settled near `target`, at which point the caller should snap to
+/// `target` exactly and stop ticking.
+pub fn step_spring(
+  pos: &mut RectScalars,
+  vel: &mut RectScalars,
+  target: RectScalars,
+  stiffness: f32,
+  damping: f32,
+  dt: f32,
+) -> bool {
+  let axes = [
+    (&mut pos.0, &mut vel.0, target.0),
+    (&mut pos.1, &mut vel.1, target.1),
+    (&mut pos.2, &mut vel.2, target.2),
+    (&mut pos.3, &mut vel.3, target.3),
+  ];
+
+  let mut settled = true;
+
+  for (p, v, target) in axes {
+    *v += (target - *p) * stiffness * dt;
+    *v *= damping;
+    *p += *v * dt;
+
+    if (target - *p).abs() > SPRING_SETTLE_EPSILON
+      || v.abs() > SPRING_SETTLE_EPSILON
+    {
+      settled = false;
+    }
+  }
+
+  settled
+}
+
+/// Cycle direction for [`WmState::focus_in_order`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CycleDirection {
+  Forward,
+  Backward,
+}
+
+/// Candidate scope for [`WmState::focus_in_order`], mirroring swayr's
+/// `CurrentWorkspace`/`AllWorkspaces` focus scopes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusScope {
+  CurrentWorkspace,
+  AllWorkspaces,
+}
+
+/// Ready-made predicates for [`WmState::focus_in_order`].
+pub mod focus_predicates {
+  use wm_common::WindowState;
+
+  use crate::models::{Container, WindowContainer};
+  use crate::traits::CommonGetters;
+
+  /// Tiling windows only (i.e. not floating or fullscreen).
+  pub fn is_tiling(window: &WindowContainer) -> bool {
+    matches!(window.state(), WindowState::Tiling)
+  }
+
+  /// Floating windows only.
+  pub fn is_floating(window: &WindowContainer) -> bool {
+    matches!(window.state(), WindowState::Floating(_))
+  }
+
+  /// Windows whose immediate parent is a tabbed/stacked (accordion)
+  /// group, as opposed to a plain horizontal/vertical split.
+  pub fn is_in_tab_stack(window: &WindowContainer) -> bool {
+    window
+      .parent()
+      .is_some_and(|parent| Container::is_tabbed_container(&parent))
+  }
 }
 
 pub struct WmState {
@@ -48,8 +269,68 @@ pub struct WmState {
   // Map to store active animation tasks
   pub animation_handles: HashMap<isize, JoinHandle<()>>,
 
-  // Drag state for Alt+Drag
+  /// Live interpolation state for in-flight window animations, keyed by
+  /// native window handle. Used to rebase a retargeted animation's
+  /// `from` rect to wherever the window actually is on screen.
+  pub animations: HashMap<isize, WindowAnimation>,
+
+  /// Named lookup for scratchpad windows, resolving a scratchpad name
+  /// to the window currently assigned to it. See
+  /// `commands::window::scratchpad`.
+  pub scratchpads: HashMap<String, WindowContainer>,
+
+  /// Every window currently parked in a scratchpad, detached from its
+  /// workspace and hidden (parallel to `ignored_windows`). Used to
+  /// recognize scratchpad windows as unmanaged during removal, and to
+  /// clean up their native windows on drop since they're not reachable
+  /// from `root_container`.
+  pub scratchpad: Vec<WindowContainer>,
+
+  /// Scrolling-tiling column state, keyed by workspace id, for
+  /// workspaces with `WorkspaceConfig.scrolling` enabled.
+  pub scrolling_layouts: HashMap<Uuid, crate::models::scrolling_layout::ScrollingLayout>,
+
+  /// Custom-drawn border overlay windows, keyed by the native window
+  /// handle they track. Only populated for windows whose effective
+  /// `BorderEffectConfig.native_drawn` is on; see
+  /// `commands::general::border_overlay` and `apply_border_effect`.
+  pub border_overlays:
+    HashMap<isize, crate::commands::general::border_overlay::BorderOverlay>,
+
+  /// Callbacks to run once when a container becomes detached, keyed by
+  /// container id. See `register_release_listener`/`notify_released`.
+  release_listeners: HashMap<Uuid, Vec<Box<dyn FnOnce(&mut WmState)>>>,
+
+  /// State for an in-progress modifier+mouse drag. See `DragState`.
   pub drag_state: Option<DragState>,
+
+  /// Time and position of the last modifier+button press that didn't
+  /// land on top of a recent one, used by `handle_mouse_move` to detect
+  /// `MouseBindingsConfig.double_click`.
+  pub last_mouse_press: Option<(Instant, Point)>,
+
+  /// Last known displayed-workspace id for each monitor, keyed by
+  /// monitor id. Diffed on every `platform_sync` pass to detect a
+  /// workspace switch worth showing `workspace_osd` for - as opposed to
+  /// a mere focus change within the same displayed workspace. See
+  /// `commands::general::workspace_osd`.
+  pub last_displayed_workspaces: HashMap<Uuid, Uuid>,
+
+  /// The lazily created workspace-switch OSD overlay window, and the
+  /// handle of its active auto-fade task (aborted and replaced if
+  /// retriggered before the previous fade finishes).
+  pub workspace_osd:
+    Option<crate::commands::general::workspace_osd::WorkspaceOsd>,
+  pub workspace_osd_fade_handle: Option<JoinHandle<()>>,
+
+  /// The config file watcher started by `populate`, kept alive for the
+  /// lifetime of the WM (dropping it stops the watch). Coalesced change
+  /// notifications land on `config_reload_rx`, drained once per
+  /// `platform_sync` pass rather than on a separate async task, since
+  /// applying a reload needs `&mut UserConfig`/`&mut WmState` for the
+  /// same duration the rest of the tick already holds them.
+  _config_watcher: Option<notify::RecommendedWatcher>,
+  pub config_reload_rx: Option<mpsc::UnboundedReceiver<()>>,
 }
 
 impl WmState {
@@ -71,7 +352,19 @@ impl WmState {
       event_tx,
       exit_tx,
       animation_handles: HashMap::new(),
+      animations: HashMap::new(),
+      scratchpads: HashMap::new(),
+      scratchpad: Vec::new(),
+      scrolling_layouts: HashMap::new(),
+      border_overlays: HashMap::new(),
+      release_listeners: HashMap::new(),
       drag_state: None,
+      last_mouse_press: None,
+      last_displayed_workspaces: HashMap::new(),
+      workspace_osd: None,
+      workspace_osd_fade_handle: None,
+      _config_watcher: None,
+      config_reload_rx: None,
     }
   }
 
@@ -79,12 +372,41 @@ impl WmState {
     &mut self,
     config: &mut UserConfig,
   ) -> anyhow::Result<()> {
+    // Per-monitor-v2 DPI awareness must be opted into before any
+    // monitor/window geometry is queried below, so mixed-DPI setups
+    // report comparable rects from the very first `sorted_monitors` call
+    // rather than only after some later, arbitrary point in startup.
+    if let Err(err) = Platform::set_process_dpi_awareness() {
+      warn!("Failed to set process DPI awareness: {}", err);
+    }
+
+    // Start watching the config file for live-reload, coalesced and
+    // applied once per `platform_sync` pass (see `config_reload_rx`).
+    // A failure to establish the watch (e.g. a read-only filesystem) is
+    // logged rather than aborting startup; live reload is then off for
+    // this session.
+    let (reload_tx, reload_rx) = mpsc::unbounded_channel();
+    match start_config_watcher(config.path(), reload_tx) {
+      Ok(watcher) => {
+        self._config_watcher = Some(watcher);
+        self.config_reload_rx = Some(reload_rx);
+      }
+      Err(err) => warn!("Failed to start config watcher: {}", err),
+    }
+
     let foreground_window = Platform::foreground_window();
 
     for native_monitor in Platform::sorted_monitors()? {
       add_monitor(native_monitor, self, config)?;
     }
 
+    // A window whose `Manage` rule assigns it to a scratchpad is
+    // detached and hidden by `manage_window` (via `run_window_rules` ->
+    // `move_to_scratchpad`) before this loop's caller ever reaches the
+    // `platform_sync` call at the end of this function - the one point
+    // where anything actually gets drawn to screen during startup. So
+    // it never flashes visible first; no separate re-hide pass is
+    // needed here.
     for native_window in Platform::manageable_windows()?.into_iter().rev()
     {
       let nearest_workspace = self
@@ -120,12 +442,54 @@ impl WmState {
       self.pending_sync.queue_workspace_to_reorder(workspace);
     }
 
+    self.reconcile_workspace_outputs(config)?;
+
     platform_sync(self, config)?;
     self.has_initialized = true;
 
     Ok(())
   }
 
+  /// Migrates workspaces pinned via `WorkspaceConfig.open_on_output` onto
+  /// their preferred monitor whenever it differs from where they're
+  /// currently displayed. Called once at startup after all monitors and
+  /// workspaces exist; it is not run again on display changes or when a
+  /// workspace is created later.
+  pub fn reconcile_workspace_outputs(
+    &mut self,
+    _config: &UserConfig,
+  ) -> anyhow::Result<()> {
+    for workspace in self.workspaces() {
+      let Some(output_name) = workspace.config().open_on_output.clone()
+      else {
+        continue;
+      };
+
+      let Some(preferred_monitor) = self.monitor_by_name(&output_name)
+      else {
+        continue;
+      };
+
+      let Some(current_monitor) = workspace.monitor() else {
+        continue;
+      };
+
+      if current_monitor.id() == preferred_monitor.id() {
+        continue;
+      }
+
+      crate::commands::container::attach_container(
+        &workspace.clone().into(),
+        &preferred_monitor.into(),
+        None,
+      )?;
+
+      self.pending_sync.queue_workspace_to_reorder(workspace);
+    }
+
+    Ok(())
+  }
+
   pub fn monitors(&self) -> Vec<Monitor> {
     self.root_container.monitors()
   }
@@ -228,10 +592,28 @@ impl WmState {
     &self,
     workspace_name: &str,
   ) -> Option<Workspace> {
-    self
-      .workspaces()
-      .into_iter()
-      .find(|workspace| workspace.config().name == workspace_name)
+    self.workspaces().into_iter().find(|workspace| {
+      workspace
+        .config()
+        .name
+        .trim()
+        .eq_ignore_ascii_case(workspace_name.trim())
+    })
+  }
+
+  /// Returns the monitor whose device/friendly name matches `name`
+  /// case-insensitively (trimming whitespace on both sides). Used to
+  /// resolve a workspace's `open_on_output` against
+  /// `NativeMonitor::device_name`, so the pinned-output string and
+  /// `workspace_by_name` agree on how names are compared.
+  pub fn monitor_by_name(&self, name: &str) -> Option<Monitor> {
+    let name = name.trim();
+
+    self.monitors().into_iter().find(|monitor| {
+      monitor.native().device_name().is_some_and(|device_name| {
+        device_name.trim().eq_ignore_ascii_case(name)
+      })
+    })
   }
 
   #[allow(clippy::too_many_lines)]
@@ -388,6 +770,27 @@ impl WmState {
         (previous_workspace_name, previous_workspace)
       }
 
+      WorkspaceTarget::Index(index) => {
+        // `index` is 1-based, as a user thinks of "workspace 3" - `0`
+        // has no corresponding workspace and must not silently resolve
+        // to the first one. Re-validates through `parse_workspace_index`
+        // rather than duplicating its `index == 0` check, so a
+        // `WorkspaceTarget::Index` built by some other path than the
+        // keybinding layer still gets the same rejection.
+        wm_common::parse_workspace_index(i32::from(index))?;
+
+        let workspace_config = config
+          .value
+          .workspaces
+          .get(usize::from(index - 1));
+
+        let name = workspace_config.map(|config| config.name.clone());
+        let workspace =
+          name.as_ref().and_then(|name| self.workspace_by_name(name));
+
+        (name, workspace)
+      }
+
       WorkspaceTarget::Direction(direction) => {
         let origin_monitor =
           origin_workspace.monitor().context("No focused monitor.")?;
@@ -446,9 +849,27 @@ impl WmState {
   }
 
   pub fn focus_target_after_removal(
-    &self,
+    &mut self,
     removed_window: &WindowContainer,
   ) -> Option<Container> {
+    // Scratchpad windows are detached and unmanaged from the tiling
+    // tree's perspective; their removal should never try to refocus
+    // something via the now-dangling workspace reference below. They're
+    // also only hidden, not released, so skip `notify_released` below.
+    if self
+      .scratchpad
+      .iter()
+      .any(|window| window.id() == removed_window.id())
+    {
+      return None;
+    }
+
+    // `removed_window` is known to be detached for good at this point,
+    // so fire its release listeners (e.g. animation/border-overlay
+    // cleanup) now instead of leaving them to run only from `Drop` at
+    // process shutdown.
+    self.notify_released(removed_window.id());
+
     if self.focused_container() != Some(removed_window.clone().into()) {
       return None;
     }
@@ -503,6 +924,65 @@ impl WmState {
       .collect()
   }
 
+  /// Cycles focus through windows filtered by `predicate`, so bindings
+  /// like `focus --next-tiling`/`focus --next-floating` can be composed
+  /// from a single primitive instead of one method per predicate.
+  pub fn focus_in_order(
+    &self,
+    direction: CycleDirection,
+    scope: FocusScope,
+    predicate: &dyn Fn(&WindowContainer) -> bool,
+  ) -> Option<WindowContainer> {
+    let focused_window =
+      self.focused_container().and_then(|c| c.as_window_container().ok());
+
+    let candidates: Vec<WindowContainer> = match scope {
+      FocusScope::CurrentWorkspace => {
+        let workspace = focused_window.as_ref()?.workspace()?;
+        workspace
+          .descendant_focus_order()
+          .filter_map(|descendant| descendant.as_window_container().ok())
+          .filter(|window| predicate(window))
+          .collect()
+      }
+      FocusScope::AllWorkspaces => self
+        .windows()
+        .into_iter()
+        .filter(|window| predicate(window))
+        .collect(),
+    };
+
+    if candidates.is_empty() {
+      return None;
+    }
+
+    let current_index = focused_window.and_then(|focused| {
+      candidates
+        .iter()
+        .position(|candidate| candidate.id() == focused.id())
+    });
+
+    let next_index = match current_index {
+      Some(index) => match direction {
+        CycleDirection::Forward => (index + 1) % candidates.len(),
+        CycleDirection::Backward => {
+          (index + candidates.len() - 1) % candidates.len()
+        }
+      },
+      // The focused window itself doesn't match `predicate` (or nothing
+      // is focused yet), so there's no current position to step from -
+      // enter the candidate list from whichever end `direction` would
+      // have been stepping towards, rather than always the front (which
+      // made `Backward` silently behave like `Forward` in this case).
+      None => match direction {
+        CycleDirection::Forward => 0,
+        CycleDirection::Backward => candidates.len() - 1,
+      },
+    };
+
+    candidates.into_iter().nth(next_index)
+  }
+
   pub fn monitor_at_point(&self, point: &Point) -> Option<Monitor> {
     self
       .monitors()
@@ -514,18 +994,142 @@ impl WmState {
       })
       .cloned()
   }
+
+  /// Bounding rect of every monitor's work area, used to confine the
+  /// cursor during an interactive drag so it can't wander onto an
+  /// unmanaged area and lose capture.
+  pub fn monitors_bounding_rect(&self) -> Option<Rect> {
+    self
+      .monitors()
+      .iter()
+      .filter_map(|monitor| monitor.native().working_rect().ok().cloned())
+      .reduce(|bounds, rect| {
+        Rect::from_ltrb(
+          bounds.left.min(rect.left),
+          bounds.top.min(rect.top),
+          bounds.right.max(rect.right),
+          bounds.bottom.max(rect.bottom),
+        )
+      })
+  }
+
+  /// Registers a one-shot callback to run the next time `container_id`
+  /// is reported detached via `notify_released`. Modeled on Zed's
+  /// `observe_release`, so cleanup of per-window auxiliary state (e.g.
+  /// in-flight animations) can live next to whatever set that state up,
+  /// instead of being threaded through every removal call site.
+  pub fn register_release_listener(
+    &mut self,
+    container_id: Uuid,
+    callback: Box<dyn FnOnce(&mut WmState)>,
+  ) {
+    self
+      .release_listeners
+      .entry(container_id)
+      .or_default()
+      .push(callback);
+  }
+
+  /// Fires and clears any listeners registered for `container_id`.
+  /// Called from the removal paths around `focus_target_after_removal`
+  /// once a container is known to be detached for good, and from `Drop`
+  /// for every window still managed at shutdown.
+  pub fn notify_released(&mut self, container_id: Uuid) {
+    let Some(listeners) = self.release_listeners.remove(&container_id)
+    else {
+      return;
+    };
+
+    for callback in listeners {
+      callback(self);
+    }
+  }
+
+  /// Wires the default release listener for a newly managed window: once
+  /// the window is released, abort and remove its in-flight move/resize
+  /// animation task so it doesn't keep running (or get orphaned in
+  /// `animation_handles`) after the window it was animating is gone.
+  pub fn register_animation_cleanup_listener(
+    &mut self,
+    window: &WindowContainer,
+  ) {
+    let handle = window.native().handle;
+
+    self.register_release_listener(
+      window.id(),
+      Box::new(move |state| {
+        if let Some(task) = state.animation_handles.remove(&handle) {
+          task.abort();
+        }
+
+        state.animations.remove(&handle);
+      }),
+    );
+  }
+
+  /// Wires the default release listener that tears down a window's
+  /// border overlay (if it has one) once the window is released, since
+  /// the overlay is a separate top-level window that isn't destroyed
+  /// along with its target.
+  pub fn register_border_overlay_cleanup_listener(
+    &mut self,
+    window: &WindowContainer,
+  ) {
+    let handle = window.native().handle;
+
+    self.register_release_listener(
+      window.id(),
+      Box::new(move |state| {
+        state.border_overlays.remove(&handle);
+      }),
+    );
+  }
+
+  /// Wires the default release listener that drops a window from
+  /// whichever scrolling-tiling column it belongs to (if any) once it's
+  /// released, and drops the column itself if that was its last window,
+  /// so `scrolling_layouts` doesn't accumulate stale entries.
+  pub fn register_scrolling_column_cleanup_listener(
+    &mut self,
+    window: &WindowContainer,
+  ) {
+    let window_id = window.id();
+
+    self.register_release_listener(
+      window_id,
+      Box::new(move |state| {
+        for layout in state.scrolling_layouts.values_mut() {
+          for column in &mut layout.columns {
+            column.windows.retain(|window| window.id() != window_id);
+          }
+
+          layout.columns.retain(|column| !column.windows.is_empty());
+        }
+      }),
+    );
+  }
 }
 
 impl Drop for WmState {
   fn drop(&mut self) {
+    // `self.scratchpad` windows are detached from `root_container`, so
+    // `self.windows()` won't find them; clean them up explicitly.
     let managed_windows = self
       .windows()
       .into_iter()
-      .map(|window| window.native().clone())
+      .chain(self.scratchpad.drain(..))
       .collect::<Vec<_>>();
 
+    // Fire release listeners (e.g. the animation-cleanup listener) before
+    // tearing down native windows, so in-flight animation tasks are
+    // aborted deterministically rather than left running against windows
+    // that no longer exist.
+    for window in &managed_windows {
+      self.notify_released(window.id());
+    }
+
     for window in managed_windows {
-      window.cleanup();
+      window.native().clone().cleanup();
     }
   }
 }